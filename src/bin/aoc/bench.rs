@@ -0,0 +1,257 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use advent_of_code_2020::{ParseError, Part, Timings};
+
+use crate::Configured;
+
+/// A step of solving a day that is timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Step {
+    const ALL: [Step; 3] = [Step::Parse, Step::Part(Part::One), Step::Part(Part::Two)];
+
+    fn of(self, timings: &Timings) -> Option<Duration> {
+        match self {
+            Step::Parse => Some(timings.parse),
+            Step::Part(Part::One) => Some(timings.part_one),
+            Step::Part(Part::Two) => timings.part_two,
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Step::Parse => "parse",
+            Step::Part(Part::One) => "part1",
+            Step::Part(Part::Two) => "part2",
+        })
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Step::ALL
+            .iter()
+            .copied()
+            .find(|step| step.to_string() == s)
+            .ok_or_else(|| format!("`{}` is not a step", s))
+    }
+}
+
+/// Repeated measurements of a single step.
+pub struct Measurement {
+    pub day: u8,
+    pub step: Step,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+/// Solves the puzzle `runs` times and summarizes how long each step took.
+pub fn measure(
+    puzzle: &Configured,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<Measurement>, ParseError> {
+    let timings = (0..runs)
+        .map(|_| puzzle.solver.time(input))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Step::ALL
+        .iter()
+        .filter_map(|&step| {
+            let mut samples = timings
+                .iter()
+                .map(|timings| step.of(timings))
+                .collect::<Option<Vec<_>>>()?;
+            samples.sort();
+            Some(Measurement {
+                day: puzzle.day,
+                step,
+                min: *samples.first()?,
+                median: samples[samples.len() / 2],
+                max: *samples.last()?,
+            })
+        })
+        .collect())
+}
+
+/// Median durations of an earlier benchmark, by day and step.
+#[derive(Default)]
+pub struct Baseline(BTreeMap<(u8, Step), Duration>);
+
+impl Baseline {
+    /// Reads a baseline written by [`Baseline::save`]. A missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(error) => return Err(format!("could not read {}: {}", path.display(), error)),
+        };
+
+        let mut medians = BTreeMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = || format!("malformed line {} in {}", index + 1, path.display());
+            let fields: Vec<_> = line.split_whitespace().collect();
+            if let [day, step, nanoseconds] = fields[..] {
+                let day = day.parse().map_err(|_| malformed())?;
+                let step = step.parse().map_err(|_| malformed())?;
+                let nanoseconds = nanoseconds.parse().map_err(|_| malformed())?;
+                medians.insert((day, step), Duration::from_nanos(nanoseconds));
+            } else {
+                return Err(malformed());
+            }
+        }
+        Ok(Baseline(medians))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut content =
+            String::from("# Median durations in nanoseconds, written by `aoc bench --save`.\n");
+        for ((day, step), median) in &self.0 {
+            content += &format!("{} {} {}\n", day, step, median.as_nanos());
+        }
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| format!("could not create {}: {}", directory.display(), error))?;
+        }
+        fs::write(path, content)
+            .map_err(|error| format!("could not write {}: {}", path.display(), error))
+    }
+
+    pub fn get(&self, measurement: &Measurement) -> Option<Duration> {
+        self.0.get(&(measurement.day, measurement.step)).copied()
+    }
+
+    pub fn update(&mut self, measurement: &Measurement) {
+        self.0
+            .insert((measurement.day, measurement.step), measurement.median);
+    }
+}
+
+/// Slowdowns smaller than this are noise rather than regressions, however large relatively.
+const NOISE: Duration = Duration::from_micros(100);
+
+/// Whether the median got slower than the baseline by more than `threshold` percent.
+pub fn is_regression(measurement: &Measurement, baseline: Duration, threshold: f64) -> bool {
+    measurement.median > baseline + NOISE && change(measurement, baseline) > threshold
+}
+
+/// Relative change of the median against the baseline, in percent.
+pub fn change(measurement: &Measurement, baseline: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (measurement.median.as_secs_f64() - baseline) / baseline * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn measurement(day: u8, step: Step, median: Duration) -> Measurement {
+        Measurement {
+            day,
+            step,
+            min: median,
+            median,
+            max: median,
+        }
+    }
+
+    /// A file in the temporary directory that is removed again after the test.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> TempFile {
+            let file_name = format!("aoc-bench-{}-{}", std::process::id(), name);
+            TempFile(std::env::temp_dir().join(file_name))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn baseline_round_trips() {
+        let file = TempFile::new("round-trip");
+        let parse = measurement(1, Step::Parse, Duration::from_nanos(1500));
+        let part_two = measurement(17, Step::Part(Part::Two), Duration::from_millis(42));
+        let mut baseline = Baseline::default();
+        baseline.update(&parse);
+        baseline.update(&part_two);
+        baseline.save(&file.0).unwrap();
+
+        let loaded = Baseline::load(&file.0).unwrap();
+        assert_eq!(loaded.get(&parse), Some(Duration::from_nanos(1500)));
+        assert_eq!(loaded.get(&part_two), Some(Duration::from_millis(42)));
+        let missing = measurement(17, Step::Part(Part::One), Duration::from_millis(1));
+        assert_eq!(loaded.get(&missing), None);
+        assert!(Baseline::load(&file.0.with_extension("missing"))
+            .unwrap()
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_baselines() {
+        let file = TempFile::new("malformed");
+        for (content, line) in [
+            ("# comment\n\n1 parse\n", 3),
+            ("1 parse 100\n1 part3 100\n", 2),
+            ("day1 parse 100\n", 1),
+            ("1 parse 1.5\n", 1),
+            ("1 parse 100 extra\n", 1),
+        ] {
+            fs::write(&file.0, content).unwrap();
+            let error = Baseline::load(&file.0).err().unwrap();
+            assert_eq!(
+                error,
+                format!("malformed line {} in {}", line, file.0.display())
+            );
+        }
+    }
+
+    #[test]
+    fn regressions_need_threshold_and_noise_floor() {
+        let baseline = Duration::from_millis(1);
+        let slower = |micros| measurement(1, Step::Parse, Duration::from_micros(micros));
+
+        assert!((change(&slower(1200), baseline) - 20.0).abs() < 1e-9);
+        assert!((change(&slower(500), baseline) + 50.0).abs() < 1e-9);
+        assert_eq!(change(&slower(500), Duration::from_secs(0)), 0.0);
+
+        assert!(is_regression(&slower(1201), baseline, 20.0));
+        assert!(!is_regression(&slower(1199), baseline, 20.0));
+
+        // Twice as slow, but only by 80µs, which is noise.
+        let small = Duration::from_micros(80);
+        let doubled = measurement(1, Step::Parse, small * 2);
+        assert!(!is_regression(&doubled, small, 20.0));
+        let above_noise = measurement(1, Step::Parse, small + NOISE + Duration::from_micros(1));
+        assert!(is_regression(&above_noise, small, 20.0));
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle input of a day is read from.
+pub enum Source {
+    /// The input checked into the repository, at `input/<day>.txt`.
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interprets `-` as stdin and anything else as a file path.
+    pub fn from_argument(argument: Option<&str>) -> Source {
+        match argument {
+            None => Source::Default,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, String> {
+        match self {
+            Source::Default => read_file(&default_path(day)),
+            Source::File(path) => read_file(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|error| format!("could not read stdin: {}", error))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{:02}.txt", day))
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|error| format!("could not read {}: {}", path.display(), error))
+}
@@ -0,0 +1,463 @@
+mod bench;
+mod input;
+mod output;
+mod parallel;
+mod verify;
+
+use std::{fs, io, path::Path, process, time::Instant};
+
+use advent_of_code_2020::{puzzle, Answer, Parameters, ParseError, Part, Solver, PUZZLES};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+
+use bench::Baseline;
+use input::Source;
+use output::Format;
+use parallel::Solved;
+use verify::Answers;
+
+fn main() {
+    let matches = App::new("aoc")
+        .about("Solves the puzzles of Advent of Code 2020")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("config")
+                .long("config")
+                .short("c")
+                .takes_value(true)
+                .value_name("PATH")
+                .global(true)
+                .help("Read the parameters of the days from PATH [default: aoc.toml, if present]"),
+        )
+        .arg(
+            Arg::with_name("set")
+                .long("set")
+                .short("s")
+                .takes_value(true)
+                .value_name("DAY.NAME=VALUE")
+                .multiple(true)
+                .number_of_values(1)
+                .global(true)
+                .help("Override a parameter, like `day01.target=2021`"),
+        )
+        .subcommand(
+            SubCommand::with_name("run")
+                .about("Solves the puzzle of a day")
+                .arg(
+                    Arg::with_name("day")
+                        .help("The day to solve, or `all` for every day")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("part")
+                        .long("part")
+                        .short("p")
+                        .takes_value(true)
+                        .possible_values(&["1", "2"])
+                        .help("Only solve the given part"),
+                )
+                .arg(
+                    Arg::with_name("input")
+                        .long("input")
+                        .short("i")
+                        .takes_value(true)
+                        .value_name("PATH")
+                        .help("Read the input from PATH, or from stdin for `-` [default: input/<day>.txt]"),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .short("f")
+                        .takes_value(true)
+                        .possible_values(&["text", "json"])
+                        .default_value("text")
+                        .help("Print sentences, or one JSON object per part"),
+                )
+                .arg(
+                    Arg::with_name("parallel")
+                        .long("parallel")
+                        .short("j")
+                        .help("Solve the days concurrently, and summarize them in a table"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about("Times parsing and both parts of a day, and compares them to a baseline")
+                .arg(
+                    Arg::with_name("day")
+                        .help("The day to benchmark, or `all` for every day")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("runs")
+                        .long("runs")
+                        .short("n")
+                        .takes_value(true)
+                        .default_value("5")
+                        .help("How often every day is solved"),
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .long("baseline")
+                        .takes_value(true)
+                        .value_name("PATH")
+                        .default_value("bench/baseline.txt")
+                        .help("The medians to compare against"),
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .long("threshold")
+                        .takes_value(true)
+                        .value_name("PERCENT")
+                        .default_value("10")
+                        .help("How much slower than the baseline a step may get"),
+                )
+                .arg(
+                    Arg::with_name("save")
+                        .long("save")
+                        .help("Store the measured medians as the new baseline"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("report")
+                .about("Writes a report about the input of a day, or lists the day's reports")
+                .arg(
+                    Arg::with_name("day")
+                        .help("The day whose input is reported on")
+                        .required(true),
+                )
+                .arg(Arg::with_name("name").help("The report to write, like `audit` for day 2"))
+                .arg(
+                    Arg::with_name("input")
+                        .long("input")
+                        .short("i")
+                        .takes_value(true)
+                        .value_name("PATH")
+                        .help("Read the input from PATH, or from stdin for `-` [default: input/<day>.txt]"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("verify")
+                .about("Checks the solutions against the known answers")
+                .arg(
+                    Arg::with_name("day")
+                        .help("The day to check, or `all` for every day")
+                        .default_value("all"),
+                )
+                .arg(
+                    Arg::with_name("answers")
+                        .long("answers")
+                        .takes_value(true)
+                        .value_name("PATH")
+                        .default_value("input/answers.txt")
+                        .help("The expected answers, as lines of `<day> <part> <answer>`"),
+                ),
+        )
+        .get_matches();
+
+    let result = match matches.subcommand() {
+        ("run", Some(arguments)) => run(arguments),
+        ("bench", Some(arguments)) => bench(arguments),
+        ("report", Some(arguments)) => report(arguments),
+        ("verify", Some(arguments)) => verify(arguments),
+        _ => Ok(()),
+    };
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+/// A day, with a solver that uses the configured parameters.
+pub struct Configured {
+    pub day: u8,
+    pub solver: Box<dyn Solver>,
+}
+
+/// The days selected by the `day` argument.
+fn select_days(arguments: &ArgMatches) -> Result<Vec<Configured>, String> {
+    let puzzles = match arguments.value_of("day").unwrap() {
+        "all" => PUZZLES.iter().collect(),
+        raw_day => {
+            let day = raw_day
+                .parse()
+                .map_err(|_| format!("`{}` is not a day", raw_day))?;
+            vec![puzzle(day).ok_or_else(|| format!("day {} is not solved", day))?]
+        }
+    };
+
+    let parameters = load_parameters(arguments)?;
+    puzzles
+        .into_iter()
+        .map(|puzzle| {
+            Ok(Configured {
+                day: puzzle.day,
+                solver: puzzle.solver(&parameters)?,
+            })
+        })
+        .collect()
+}
+
+/// Reads the configuration file, and applies the overrides given with `--set`.
+fn load_parameters(arguments: &ArgMatches) -> Result<Parameters, String> {
+    let (path, required) = match arguments.value_of("config") {
+        Some(path) => (Path::new(path), true),
+        None => (Path::new("aoc.toml"), false),
+    };
+    let mut parameters = match fs::read_to_string(path) {
+        Ok(document) => Parameters::parse(&document)
+            .map_err(|error| format!("invalid configuration in {}: {}", path.display(), error))?,
+        Err(error) if error.kind() == io::ErrorKind::NotFound && !required => Parameters::default(),
+        Err(error) => return Err(format!("could not read {}: {}", path.display(), error)),
+    };
+
+    for assignment in arguments.values_of("set").into_iter().flatten() {
+        parameters.set(assignment)?;
+    }
+    Ok(parameters)
+}
+
+fn run(arguments: &ArgMatches) -> Result<(), String> {
+    let parts = match arguments.value_of("part") {
+        Some("1") => vec![Part::One],
+        Some("2") => vec![Part::Two],
+        _ => Part::BOTH.to_vec(),
+    };
+
+    let source = Source::from_argument(arguments.value_of("input"));
+    let format = Format::from_argument(arguments.value_of("format"));
+
+    let puzzles = select_days(arguments)?;
+    if puzzles.len() > 1 && !matches!(source, Source::Default) {
+        return Err("an input can only be given for a single day".to_string());
+    }
+
+    if arguments.is_present("parallel") {
+        if !matches!(source, Source::Default) {
+            return Err("an input can't be given when solving in parallel".to_string());
+        }
+        return run_parallel(puzzles, parts, format);
+    }
+
+    let mut malformed = Vec::new();
+    for puzzle in puzzles {
+        let input = source.read(puzzle.day)?;
+        if let Err(error) = print_solutions(&puzzle, &input, &parts, format) {
+            eprintln!("{}", error.diagnostic(&input));
+            malformed.push(puzzle.day.to_string());
+        }
+    }
+
+    if malformed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("malformed input for day {}", malformed.join(", ")))
+    }
+}
+
+fn print_solutions(
+    puzzle: &Configured,
+    input: &str,
+    parts: &[Part],
+    format: Format,
+) -> Result<(), ParseError> {
+    if format == Format::Text {
+        println!("Day {}", puzzle.day);
+    }
+    for &part in parts {
+        let solved = Solved::new(puzzle, input, part);
+        output::print_part(puzzle.day, &solved, format);
+        solved.result?;
+    }
+    Ok(())
+}
+
+fn run_parallel(puzzles: Vec<Configured>, parts: Vec<Part>, format: Format) -> Result<(), String> {
+    let start = Instant::now();
+    let mut outcomes = Vec::new();
+    for outcome in parallel::solve_all(puzzles, parts.clone()) {
+        if format == Format::Text {
+            println!("Day {} ({:.1?})", outcome.day, outcome.duration);
+        }
+        for solved in &outcome.solved {
+            output::print_part(outcome.day, solved, format);
+            if let Err(error) = &solved.result {
+                eprintln!("{}", error.diagnostic(&outcome.input));
+            }
+        }
+        if let Some(failure) = &outcome.failure {
+            eprintln!("error: day {} failed: {}", outcome.day, failure);
+        }
+        outcomes.push(outcome);
+    }
+
+    if format == Format::Text {
+        println!();
+        output::print_summary(&mut outcomes, &parts);
+        println!("Solved {} days in {:.1?}.", outcomes.len(), start.elapsed());
+    }
+
+    let failed: Vec<_> = outcomes
+        .iter()
+        .filter(|outcome| !outcome.is_success())
+        .map(|outcome| outcome.day.to_string())
+        .collect();
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("day {} failed", failed.join(", ")))
+    }
+}
+
+fn bench(arguments: &ArgMatches) -> Result<(), String> {
+    let puzzles = select_days(arguments)?;
+    let runs: usize = match arguments.value_of("runs").unwrap().parse() {
+        Ok(runs) if runs > 0 => runs,
+        _ => return Err("the number of runs must be a positive number".to_string()),
+    };
+    let threshold: f64 = arguments
+        .value_of("threshold")
+        .unwrap()
+        .parse()
+        .map_err(|_| "the threshold must be a number".to_string())?;
+    let path = Path::new(arguments.value_of("baseline").unwrap());
+    let save = arguments.is_present("save");
+    let mut baseline = Baseline::load(path)?;
+
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build, use `cargo run --release`");
+    }
+
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "day", "step", "min", "median", "max", "baseline"
+    );
+    let mut regressions = 0;
+    for puzzle in puzzles {
+        let input = Source::Default.read(puzzle.day)?;
+        let measurements = bench::measure(&puzzle, &input, runs).map_err(|error| {
+            format!(
+                "malformed input for day {}\n{}",
+                puzzle.day,
+                error.diagnostic(&input)
+            )
+        })?;
+
+        for measurement in &measurements {
+            let mut row = format!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+                measurement.day,
+                measurement.step,
+                format!("{:.1?}", measurement.min),
+                format!("{:.1?}", measurement.median),
+                format!("{:.1?}", measurement.max),
+            );
+            if let Some(median) = baseline.get(measurement) {
+                let change = bench::change(measurement, median);
+                row += &format!("  {:>10}  {:+.0}%", format!("{:.1?}", median), change);
+                if bench::is_regression(measurement, median, threshold) {
+                    row += "  regression";
+                    regressions += 1;
+                }
+            }
+            println!("{}", row);
+        }
+
+        if save {
+            measurements
+                .iter()
+                .for_each(|measurement| baseline.update(measurement));
+        }
+    }
+
+    if save {
+        baseline.save(path)
+    } else if regressions > 0 {
+        Err(format!(
+            "{} step(s) got more than {}% slower than the baseline",
+            regressions, threshold
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn report(arguments: &ArgMatches) -> Result<(), String> {
+    if arguments.value_of("day") == Some("all") {
+        return Err("reports are written for a single day".to_string());
+    }
+    let puzzle = select_days(arguments)?.remove(0);
+    let reports = puzzle.solver.reports();
+    let listing = || match reports {
+        [] => format!("day {} has no reports", puzzle.day),
+        _ => format!(
+            "the reports of day {} are: {}",
+            puzzle.day,
+            reports.join(", ")
+        ),
+    };
+
+    let name = match arguments.value_of("name") {
+        Some(name) => name,
+        None => {
+            println!("{}", listing());
+            return Ok(());
+        }
+    };
+    let input = Source::from_argument(arguments.value_of("input")).read(puzzle.day)?;
+    match puzzle.solver.report(&input, name) {
+        Ok(Some(report)) => {
+            println!("{}", report);
+            Ok(())
+        }
+        Ok(None) => Err(format!("there is no report `{}`, {}", name, listing())),
+        Err(error) => {
+            eprintln!("{}", error.diagnostic(&input));
+            Err(format!("malformed input for day {}", puzzle.day))
+        }
+    }
+}
+
+fn verify(arguments: &ArgMatches) -> Result<(), String> {
+    let puzzles = select_days(arguments)?;
+    let answers = Answers::load(Path::new(arguments.value_of("answers").unwrap()))?;
+
+    let mut failures = 0;
+    for puzzle in puzzles {
+        let input = Source::Default.read(puzzle.day)?;
+        for &part in &Part::BOTH {
+            let expected = answers.get(puzzle.day, part);
+            let status = match (puzzle.solver.solve(&input, part), expected) {
+                (Err(error), _) => {
+                    eprintln!("{}", error.diagnostic(&input));
+                    failures += 1;
+                    "FAIL (malformed input)".to_string()
+                }
+                (Ok(None), None) => continue,
+                (Ok(None), Some(expected)) => {
+                    failures += 1;
+                    format!("FAIL (not solved, expected {})", expected)
+                }
+                (Ok(Some(Answer::Missing(reason))), None) => format!("no answer: {}", reason),
+                (Ok(Some(Answer::Missing(reason))), Some(expected)) => {
+                    failures += 1;
+                    format!("FAIL (no answer: {}, expected {})", reason, expected)
+                }
+                (Ok(Some(_)), None) => "no known answer".to_string(),
+                (Ok(Some(answer)), Some(expected)) if answer.to_string() == expected => {
+                    "ok".to_string()
+                }
+                (Ok(Some(answer)), Some(expected)) => {
+                    failures += 1;
+                    format!("FAIL (got {}, expected {})", answer, expected)
+                }
+            };
+            println!("Day {:>2}, {} part: {}", puzzle.day, part, status);
+        }
+    }
+
+    if failures > 0 {
+        Err(format!("{} answer(s) are wrong", failures))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use advent_of_code_2020::{Answer, Part};
+use serde::Serialize;
+
+use crate::parallel::{Outcome, Solved};
+
+/// How the solutions are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Sentences for people reading the terminal.
+    Text,
+    /// One JSON object per line and part, see [`Record`].
+    Json,
+}
+
+impl Format {
+    pub fn from_argument(argument: Option<&str>) -> Format {
+        match argument {
+            Some("json") => Format::Json,
+            _ => Format::Text,
+        }
+    }
+}
+
+/// The outcome of solving one part. The field names are stable, since scripts rely on them.
+#[derive(Serialize)]
+pub struct Record {
+    pub day: u8,
+    /// `1` or `2`.
+    pub part: u8,
+    /// The answer as text, so that large numbers survive parsers with floating point numbers.
+    /// `None` if the part is not solved yet, the input is malformed or has no answer.
+    pub answer: Option<String>,
+    /// `"number"` or `"text"`, or `None` if there is no answer.
+    pub answer_type: Option<&'static str>,
+    /// How long parsing the input and solving the part took.
+    pub duration_ns: u128,
+    /// Why the input could not be parsed, or why it has no answer.
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(day: u8, solved: &Solved) -> Record {
+        let (answer, answer_type, error) = match &solved.result {
+            Ok(Some(Answer::Number(number))) => (Some(number.to_string()), Some("number"), None),
+            Ok(Some(Answer::Text(text))) => (Some(text.clone()), Some("text"), None),
+            Ok(Some(Answer::Missing(reason))) => (None, None, Some(reason.clone())),
+            Ok(None) => (None, None, None),
+            Err(error) => (None, None, Some(error.to_string())),
+        };
+        Record {
+            day,
+            part: match solved.part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            answer,
+            answer_type,
+            duration_ns: solved.duration.as_nanos(),
+            error,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records only contain serializable values")
+    }
+}
+
+/// Prints a solved part. Malformed input is left to the caller, since it affects the whole day.
+pub fn print_part(day: u8, solved: &Solved, format: Format) {
+    match format {
+        Format::Text => match &solved.result {
+            Ok(Some(Answer::Missing(reason))) => {
+                println!("The {} part has no answer: {}", solved.part, reason)
+            }
+            Ok(Some(solution)) => println!("The {} solution is:\n{}", solved.part, solution),
+            Ok(None) => println!("The {} part is not solved yet.", solved.part),
+            Err(_) => {}
+        },
+        Format::Json => println!("{}", Record::new(day, solved).to_json()),
+    }
+}
+
+/// Prints a table of the answers and durations of all days, ordered by day.
+pub fn print_summary(outcomes: &mut [Outcome], parts: &[Part]) {
+    outcomes.sort_by_key(|outcome| outcome.day);
+
+    print!("{:>3}", "day");
+    for part in parts {
+        print!("  {:<20}", format!("{} part", part));
+    }
+    println!("  {:>10}", "duration");
+
+    for outcome in outcomes.iter() {
+        print!("{:>3}", outcome.day);
+        for &part in parts {
+            let solved = outcome.solved.iter().find(|solved| solved.part == part);
+            let cell = match solved.map(|solved| &solved.result) {
+                Some(Ok(Some(Answer::Missing(_)))) => "no answer".to_string(),
+                Some(Ok(Some(answer))) => answer.to_string(),
+                Some(Ok(None)) => "not solved".to_string(),
+                Some(Err(_)) => "malformed input".to_string(),
+                None if outcome.failure.is_some() => "failed".to_string(),
+                None => "-".to_string(),
+            };
+            print!("  {:<20}", cell);
+        }
+        println!("  {:>10}", format!("{:.1?}", outcome.duration));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code_2020::ParseError;
+    use std::time::Duration;
+
+    fn json(part: Part, result: Result<Option<Answer>, ParseError>) -> String {
+        let solved = Solved {
+            part,
+            result,
+            duration: Duration::from_micros(1500),
+        };
+        Record::new(7, &solved).to_json()
+    }
+
+    #[test]
+    fn serializes_records() {
+        assert_eq!(
+            json(Part::One, Ok(Some(Answer::Number(20899048083289)))),
+            r#"{"day":7,"part":1,"answer":"20899048083289","answer_type":"number","duration_ns":1500000,"error":null}"#
+        );
+        assert_eq!(
+            json(Part::Two, Ok(Some(Answer::Text("no seat".to_string())))),
+            r#"{"day":7,"part":2,"answer":"no seat","answer_type":"text","duration_ns":1500000,"error":null}"#
+        );
+        assert_eq!(
+            json(
+                Part::Two,
+                Ok(Some(Answer::Missing("no free seat".to_string())))
+            ),
+            r#"{"day":7,"part":2,"answer":null,"answer_type":null,"duration_ns":1500000,"error":"no free seat"}"#
+        );
+        assert_eq!(
+            json(Part::Two, Ok(None)),
+            r#"{"day":7,"part":2,"answer":null,"answer_type":null,"duration_ns":1500000,"error":null}"#
+        );
+        let error = ParseError {
+            line: 3,
+            column: 5,
+            expected: "`bags`".to_string(),
+            section: None,
+        };
+        assert_eq!(
+            json(Part::One, Err(error)),
+            r#"{"day":7,"part":1,"answer":null,"answer_type":null,"duration_ns":1500000,"error":"expected `bags` at line 3, column 5"}"#
+        );
+    }
+}
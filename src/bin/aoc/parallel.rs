@@ -0,0 +1,101 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2020::{Answer, ParseError, Part};
+use rayon::prelude::*;
+
+use crate::{input::Source, Configured};
+
+/// The result of solving a single part.
+pub struct Solved {
+    pub part: Part,
+    pub result: Result<Option<Answer>, ParseError>,
+    /// How long parsing the input and solving the part took.
+    pub duration: Duration,
+}
+
+impl Solved {
+    pub fn new(puzzle: &Configured, input: &str, part: Part) -> Solved {
+        let start = Instant::now();
+        let result = puzzle.solver.solve(input, part);
+        Solved {
+            part,
+            result,
+            duration: start.elapsed(),
+        }
+    }
+}
+
+/// Everything that happened while solving a day on the thread pool.
+pub struct Outcome {
+    pub day: u8,
+    pub input: String,
+    /// The solved parts, up to the first one that failed to parse the input.
+    pub solved: Vec<Solved>,
+    /// Why the day could not be solved, if reading the input failed or a solver panicked.
+    pub failure: Option<String>,
+    pub duration: Duration,
+}
+
+impl Outcome {
+    pub fn is_success(&self) -> bool {
+        self.failure.is_none() && self.solved.iter().all(|solved| solved.result.is_ok())
+    }
+}
+
+/// Solves the days concurrently, sending every day's outcome as soon as it is done.
+pub fn solve_all(puzzles: Vec<Configured>, parts: Vec<Part>) -> Receiver<Outcome> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        puzzles
+            .into_par_iter()
+            .for_each_with(sender, |sender, puzzle| {
+                // The receiver only hangs up if the main thread is gone anyway.
+                let _ = sender.send(solve_day(&puzzle, &parts));
+            });
+    });
+    receiver
+}
+
+fn solve_day(puzzle: &Configured, parts: &[Part]) -> Outcome {
+    let start = Instant::now();
+    let mut outcome = Outcome {
+        day: puzzle.day,
+        input: String::new(),
+        solved: Vec::new(),
+        failure: None,
+        duration: Duration::default(),
+    };
+
+    match Source::Default.read(puzzle.day) {
+        Ok(input) => outcome.input = input,
+        Err(message) => outcome.failure = Some(message),
+    }
+    if outcome.failure.is_none() {
+        for &part in parts {
+            let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+                Solved::new(puzzle, &outcome.input, part)
+            }));
+            match solved {
+                Ok(solved) => {
+                    let is_malformed = solved.result.is_err();
+                    outcome.solved.push(solved);
+                    if is_malformed {
+                        break;
+                    }
+                }
+                Err(_) => {
+                    outcome.failure = Some(format!("the {} part panicked", part));
+                    break;
+                }
+            }
+        }
+    }
+
+    outcome.duration = start.elapsed();
+    outcome
+}
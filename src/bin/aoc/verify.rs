@@ -0,0 +1,87 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use advent_of_code_2020::Part;
+
+/// The known answers to the checked in inputs, by day and part.
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+impl Answers {
+    /// Reads lines of `<day> <part> <answer>`, where the answer is the rest of the line.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+
+        let mut answers = BTreeMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = || format!("malformed line {} in {}", index + 1, path.display());
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let day = fields
+                .next()
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(malformed)?;
+            let part = match fields.next() {
+                Some("1") => Part::One,
+                Some("2") => Part::Two,
+                _ => return Err(malformed()),
+            };
+            let answer = fields.next().map(str::trim).ok_or_else(malformed)?;
+            answers.insert((day, part), answer.to_string());
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(name: &str, content: &str) -> Result<Answers, String> {
+        let file_name = format!("aoc-verify-{}-{}", std::process::id(), name);
+        let path = std::env::temp_dir().join(file_name);
+        fs::write(&path, content).unwrap();
+        let answers = Answers::load(&path);
+        fs::remove_file(&path).unwrap();
+        answers.map_err(|error| error.replace(&path.display().to_string(), "answers"))
+    }
+
+    #[test]
+    fn keeps_spaces_within_answers() {
+        let answers = load(
+            "spaces",
+            "# day part answer\n\n1 1 1006875\n 1 2 no 3 entries  sum up to 2020 \n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("1006875"));
+        assert_eq!(
+            answers.get(1, Part::Two),
+            Some("no 3 entries  sum up to 2020")
+        );
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        for (content, line) in [
+            ("1 1 5\n1\n", 2),
+            ("1 1\n", 1),
+            ("1 3 5\n", 1),
+            ("one 1 5\n", 1),
+            ("# comment\n1 part1 5\n", 2),
+        ] {
+            assert_eq!(
+                load("malformed", content).err(),
+                Some(format!("malformed line {} in answers", line)),
+                "{:?}",
+                content
+            );
+        }
+    }
+}
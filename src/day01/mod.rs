@@ -0,0 +1,523 @@
+use itertools::Itertools;
+use serde::Deserialize;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
+
+use crate::{
+    parsing::{self, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day01 {
+    /// What the entries have to sum up to.
+    pub target: u64,
+}
+
+impl Default for Day01 {
+    fn default() -> Self {
+        Day01 { target: 2020 }
+    }
+}
+
+impl Day for Day01 {
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_numbers(input)
+    }
+
+    fn part_one(&self, numbers: &Self::Input) -> Answer {
+        self.answer(numbers, 2)
+    }
+
+    fn part_two(&self, numbers: &Self::Input) -> Option<Answer> {
+        Some(self.answer(numbers, 3))
+    }
+
+    const REPORTS: &'static [&'static str] = &["entries", "groupings", "smallest"];
+
+    fn report(&self, numbers: &Self::Input, name: &str) -> Option<String> {
+        match name {
+            "entries" => Some(entries_report(numbers, self.target)),
+            "groupings" => Some(groupings_report(numbers, self.target)),
+            "smallest" => Some(smallest_report(numbers, self.target)),
+            _ => None,
+        }
+    }
+}
+
+impl Day01 {
+    fn answer(&self, numbers: &[u64], size_of_grouping: usize) -> Answer {
+        match solve(numbers, size_of_grouping, self.target) {
+            Ok(product) => product.into(),
+            Err(reason) => Answer::Missing(reason),
+        }
+    }
+}
+
+/// The product of the summands, or why there is none.
+fn solve(numbers: &[u64], size_of_grouping: usize, target: u64) -> Result<u64, String> {
+    let summands = find_summands(numbers, size_of_grouping, target)
+        .ok_or_else(|| format!("no {} entries sum up to {}", size_of_grouping, target))?;
+    summands
+        .iter()
+        .try_fold(1u64, |product, &summand| product.checked_mul(summand))
+        .ok_or_else(|| {
+            format!(
+                "the product of {} is too large",
+                summands.iter().join(" * ")
+            )
+        })
+}
+
+/// The parts with the number of entries they sum up.
+const PARTS: [(&str, usize); 2] = [("first", 2), ("second", 3)];
+
+/// The entries that each part multiplies.
+fn entries_report(numbers: &[u64], target: u64) -> String {
+    let lines: Vec<_> = PARTS
+        .iter()
+        .map(|&(part, size_of_grouping)| {
+            let entries = match find_summands(numbers, size_of_grouping, target) {
+                Some(summands) => format!("{} = {}", summands.iter().join(" + "), target),
+                None => format!("no {} entries sum up to {}", size_of_grouping, target),
+            };
+            format!("{} part: {}", part, entries)
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Every grouping of the sizes of both parts, and how many there are.
+fn groupings_report(numbers: &[u64], target: u64) -> String {
+    let sections: Vec<_> = PARTS
+        .iter()
+        .map(|&(_, size_of_grouping)| {
+            let mut section = format!(
+                "groupings of {} entries that sum up to {}: {}",
+                size_of_grouping,
+                target,
+                count_groupings(numbers, size_of_grouping, target)
+            );
+            for grouping in all_groupings(numbers, size_of_grouping, target) {
+                section += &format!("\n  {}", describe_grouping(numbers, &grouping));
+            }
+            section
+        })
+        .collect();
+    sections.join("\n\n")
+}
+
+/// The fewest entries of any number that sum up to the target.
+fn smallest_report(numbers: &[u64], target: u64) -> String {
+    match smallest_grouping(numbers, target) {
+        Ok(Some(grouping)) => format!(
+            "{} entries are the fewest that sum up to {}\n  {}",
+            grouping.len(),
+            target,
+            describe_grouping(numbers, &grouping)
+        ),
+        Ok(None) => format!("no entries sum up to {}", target),
+        Err(reason) => reason,
+    }
+}
+
+/// The 1-based positions of the entries in the input, and their values.
+fn describe_grouping(numbers: &[u64], grouping: &[usize]) -> String {
+    format!(
+        "entries {}: {}",
+        grouping.iter().map(|index| index + 1).join(", "),
+        grouping.iter().map(|&index| numbers[index]).join(" + ")
+    )
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<u64>, ParseError> {
+    parsing::lines(input).map(|line| line.number()).collect()
+}
+
+/// Finds `size_of_grouping` entries that sum up to `target`, and returns them in ascending order.
+/// Every entry is used at most once, but entries may have the same value.
+///
+/// Pairs are found with a hash set in linear time, triples by moving two pointers through the
+/// sorted entries in quadratic time, and larger groupings by meeting in the middle, combining
+/// groupings of half the size.
+pub fn find_summands(numbers: &[u64], size_of_grouping: usize, target: u64) -> Option<Vec<u64>> {
+    let mut summands = match size_of_grouping {
+        0 => Some(Vec::new()).filter(|_| target == 0),
+        1 => numbers
+            .iter()
+            .find(|&&number| number == target)
+            .map(|&number| vec![number]),
+        2 => find_pair(numbers, target),
+        3 => find_triple(numbers, target),
+        _ => find_by_meeting_in_the_middle(numbers, size_of_grouping, target),
+    }?;
+    summands.sort_unstable();
+    Some(summands)
+}
+
+fn find_pair(numbers: &[u64], target: u64) -> Option<Vec<u64>> {
+    let mut seen = HashSet::new();
+    for &number in numbers {
+        if let Some(complement) = target.checked_sub(number) {
+            if seen.contains(&complement) {
+                return Some(vec![complement, number]);
+            }
+        }
+        seen.insert(number);
+    }
+    None
+}
+
+fn find_triple(numbers: &[u64], target: u64) -> Option<Vec<u64>> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+
+    for (index, &first) in sorted.iter().enumerate() {
+        // All following entries are at least as large, so none of them fit either.
+        let rest = target.checked_sub(first)?;
+        let mut low = index + 1;
+        let mut high = sorted.len() - 1;
+        while low < high {
+            match (sorted[low] as u128 + sorted[high] as u128).cmp(&(rest as u128)) {
+                Ordering::Equal => return Some(vec![first, sorted[low], sorted[high]]),
+                Ordering::Less => low += 1,
+                Ordering::Greater => high -= 1,
+            }
+        }
+    }
+    None
+}
+
+fn find_by_meeting_in_the_middle(
+    numbers: &[u64],
+    size_of_grouping: usize,
+    target: u64,
+) -> Option<Vec<u64>> {
+    let sum = |indices: &[usize]| {
+        indices
+            .iter()
+            .try_fold(0u64, |sum, &index| sum.checked_add(numbers[index]))
+    };
+
+    let half = size_of_grouping / 2;
+    let mut halves_by_sum: HashMap<u64, Vec<Vec<usize>>> = HashMap::new();
+    for indices in (0..numbers.len()).combinations(half) {
+        if let Some(sum) = sum(&indices).filter(|&sum| sum <= target) {
+            halves_by_sum.entry(sum).or_default().push(indices);
+        }
+    }
+
+    (0..numbers.len())
+        .combinations(size_of_grouping - half)
+        .find_map(|indices| {
+            let rest = target.checked_sub(sum(&indices)?)?;
+            let other = halves_by_sum
+                .get(&rest)?
+                .iter()
+                .find(|other| other.iter().all(|index| !indices.contains(index)))?;
+            Some(
+                other
+                    .iter()
+                    .chain(&indices)
+                    .map(|&index| numbers[index])
+                    .collect(),
+            )
+        })
+}
+
+/// Every way of choosing `size_of_grouping` entries that sum up to `target`, each given by the
+/// ascending indices of its entries. Entries with the same value at different indices make for
+/// different groupings. The groupings are ordered by their indices.
+pub fn all_groupings(numbers: &[u64], size_of_grouping: usize, target: u64) -> Vec<Vec<usize>> {
+    let mut by_value: Vec<usize> = (0..numbers.len()).collect();
+    by_value.sort_by_key(|&index| numbers[index]);
+
+    let mut groupings = Vec::new();
+    collect_groupings(
+        numbers,
+        &by_value,
+        size_of_grouping,
+        target,
+        &mut Vec::new(),
+        &mut groupings,
+    );
+    for grouping in &mut groupings {
+        grouping.sort_unstable();
+    }
+    groupings.sort_unstable();
+    groupings
+}
+
+/// Extends `chosen` by entries from `candidates`, which are sorted by their values.
+fn collect_groupings(
+    numbers: &[u64],
+    candidates: &[usize],
+    size_of_grouping: usize,
+    target: u64,
+    chosen: &mut Vec<usize>,
+    groupings: &mut Vec<Vec<usize>>,
+) {
+    if size_of_grouping == 0 {
+        if target == 0 {
+            groupings.push(chosen.clone());
+        }
+        return;
+    }
+
+    for (position, &index) in candidates.iter().enumerate() {
+        if candidates.len() - position < size_of_grouping {
+            break;
+        }
+        // All following candidates are at least as large, so none of them fit either.
+        let rest = match target.checked_sub(numbers[index]) {
+            Some(rest) => rest,
+            None => break,
+        };
+        chosen.push(index);
+        collect_groupings(
+            numbers,
+            &candidates[position + 1..],
+            size_of_grouping - 1,
+            rest,
+            chosen,
+            groupings,
+        );
+        chosen.pop();
+    }
+}
+
+/// How many groupings [`all_groupings`] returns, counted by the sums of smaller groupings instead
+/// of listing them.
+pub fn count_groupings(numbers: &[u64], size_of_grouping: usize, target: u64) -> usize {
+    // The number of groupings of each size by their sum, among the entries seen so far.
+    let mut counts: Vec<HashMap<u64, usize>> = vec![HashMap::new(); size_of_grouping + 1];
+    counts[0].insert(0, 1);
+
+    for &number in numbers {
+        // Going from large to small groupings uses every entry at most once.
+        for size in (1..=size_of_grouping).rev() {
+            let extended: Vec<_> = counts[size - 1]
+                .iter()
+                .filter_map(|(&sum, &count)| {
+                    let sum = sum.checked_add(number).filter(|&sum| sum <= target)?;
+                    Some((sum, count))
+                })
+                .collect();
+            for (sum, count) in extended {
+                *counts[size].entry(sum).or_default() += count;
+            }
+        }
+    }
+
+    counts[size_of_grouping].get(&target).copied().unwrap_or(0)
+}
+
+/// The fewest entries, of any number, that sum up to `target`, given by their ascending indices.
+/// Among equally small groupings, the one whose last entry comes first is chosen.
+///
+/// This is a subset-sum over all sums up to `target`, so it takes time and memory proportional
+/// to the number of entries times `target`. Targets whose tables would take more than
+/// [`MAX_TABLE_BITS`] are refused.
+pub fn smallest_grouping(numbers: &[u64], target: u64) -> Result<Option<Vec<usize>>, String> {
+    // A bit per entry and sum, and a 32 bit size per sum.
+    let bits = (u128::from(target) + 1) * (numbers.len() as u128 + 32);
+    if bits > u128::from(MAX_TABLE_BITS) {
+        return Err(format!("{} is too large to tabulate", target));
+    }
+    let target = target as usize;
+    let sums = target + 1;
+
+    // The size of the smallest grouping for each sum, among the entries seen so far. As there
+    // are fewer entries than `u32::MAX`, that stands for sums without a grouping.
+    let mut smallest = vec![u32::MAX; sums];
+    smallest[0] = 0;
+    // Whether an entry is part of the smallest grouping for a sum among the entries up to it.
+    let mut taken = Bits::new(numbers.len() * sums);
+
+    for (index, &number) in numbers.iter().enumerate() {
+        let number = match usize::try_from(number) {
+            Ok(number) if number <= target => number,
+            _ => continue,
+        };
+        // Going from large to small sums uses every entry at most once.
+        for sum in (number..sums).rev() {
+            let size = smallest[sum - number].saturating_add(1);
+            if size < smallest[sum] {
+                smallest[sum] = size;
+                taken.set(index * sums + sum);
+            }
+        }
+    }
+
+    if smallest[target] == u32::MAX {
+        return Ok(None);
+    }
+    let mut grouping = Vec::new();
+    let mut sum = target;
+    for index in (0..numbers.len()).rev() {
+        if sum == 0 {
+            break;
+        }
+        if taken.get(index * sums + sum) {
+            grouping.push(index);
+            sum -= numbers[index] as usize;
+        }
+    }
+    grouping.reverse();
+    Ok(Some(grouping))
+}
+
+/// How large the tables of [`smallest_grouping`] may get, which is 512 MiB.
+pub const MAX_TABLE_BITS: u64 = 1 << 32;
+
+/// A fixed number of bits, all unset at first.
+struct Bits(Vec<u64>);
+
+impl Bits {
+    fn new(len: usize) -> Bits {
+        Bits(vec![0; len.div_ceil(64)])
+    }
+
+    fn get(&self, index: usize) -> bool {
+        self.0[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    fn set(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEMO_INPUT: &str = "1721
+    979
+    366
+    299
+    675
+    1456";
+
+    #[test]
+    fn first_demo_solution() {
+        let solution = solve(&parse_numbers(DEMO_INPUT).unwrap(), 2, 2020);
+        assert_eq!(solution, Ok(514579));
+    }
+
+    #[test]
+    fn second_demo_solution() {
+        let solution = solve(&parse_numbers(DEMO_INPUT).unwrap(), 3, 2020);
+        assert_eq!(solution, Ok(241861950));
+    }
+
+    #[test]
+    fn reports_overflowing_products() {
+        let numbers = [30_000_000, 30_000_001, 29_999_999, 5];
+        assert_eq!(
+            solve(&numbers, 3, 90_000_000),
+            Err("the product of 29999999 * 30000000 * 30000001 is too large".to_string())
+        );
+        assert_eq!(solve(&numbers, 2, 30_000_005), Ok(150_000_000));
+    }
+
+    #[test]
+    fn uses_every_entry_once() {
+        assert_eq!(find_summands(&[1010, 7], 2, 2020), None);
+        assert_eq!(
+            find_summands(&[1010, 7, 1010], 2, 2020),
+            Some(vec![1010, 1010])
+        );
+        assert_eq!(find_summands(&[5, 10, 1005], 3, 2020), None);
+    }
+
+    #[test]
+    fn finds_larger_groupings() {
+        let numbers = parse_numbers(DEMO_INPUT).unwrap();
+        assert_eq!(
+            find_summands(&numbers, 4, 299 + 366 + 675 + 979),
+            Some(vec![299, 366, 675, 979])
+        );
+        assert_eq!(find_summands(&numbers, 5, 10), None);
+        assert_eq!(find_summands(&numbers, 0, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn lists_groupings_of_repeated_values() {
+        let numbers = [1010, 1010, 7, 1010];
+        assert_eq!(
+            all_groupings(&numbers, 2, 2020),
+            vec![vec![0, 1], vec![0, 3], vec![1, 3]]
+        );
+        assert_eq!(count_groupings(&numbers, 2, 2020), 3);
+
+        let numbers = parse_numbers(DEMO_INPUT).unwrap();
+        assert_eq!(all_groupings(&numbers, 3, 2020), vec![vec![1, 2, 4]]);
+        assert_eq!(count_groupings(&numbers, 3, 2020), 1);
+        assert_eq!(count_groupings(&[0; 6], 3, 0), 20);
+    }
+
+    #[test]
+    fn finds_smallest_grouping_of_any_size() {
+        let numbers = parse_numbers(DEMO_INPUT).unwrap();
+        assert_eq!(smallest_grouping(&numbers, 2020), Ok(Some(vec![0, 3])));
+        assert_eq!(
+            smallest_grouping(&numbers, 366 + 299 + 675 + 1456),
+            Ok(Some(vec![2, 3, 4, 5]))
+        );
+        assert_eq!(smallest_grouping(&numbers, 1), Ok(None));
+        assert_eq!(smallest_grouping(&[5, 5, 3, 2], 10), Ok(Some(vec![0, 1])));
+        assert_eq!(smallest_grouping(&[], 0), Ok(Some(vec![])));
+    }
+
+    #[test]
+    fn reports_entries_of_both_parts() {
+        let numbers = parse_numbers(DEMO_INPUT).unwrap();
+        assert_eq!(
+            entries_report(&numbers, 2020),
+            "first part: 299 + 1721 = 2020\nsecond part: 366 + 675 + 979 = 2020"
+        );
+        assert_eq!(
+            entries_report(&numbers, 1),
+            "first part: no 2 entries sum up to 1\nsecond part: no 3 entries sum up to 1"
+        );
+    }
+
+    #[test]
+    fn reports_every_grouping() {
+        let numbers = parse_numbers(DEMO_INPUT).unwrap();
+        assert_eq!(
+            groupings_report(&numbers, 2020),
+            "groupings of 2 entries that sum up to 2020: 1\n  entries 1, 4: 1721 + 299\n\n\
+             groupings of 3 entries that sum up to 2020: 1\n  entries 2, 3, 5: 979 + 366 + 675"
+        );
+    }
+
+    #[test]
+    fn reports_smallest_grouping() {
+        let numbers = parse_numbers(DEMO_INPUT).unwrap();
+        assert_eq!(
+            smallest_report(&numbers, 2020),
+            "2 entries are the fewest that sum up to 2020\n  entries 1, 4: 1721 + 299"
+        );
+        assert_eq!(smallest_report(&numbers, 1), "no entries sum up to 1");
+    }
+
+    #[test]
+    fn refuses_targets_too_large_to_tabulate() {
+        let numbers = parse_numbers(DEMO_INPUT).unwrap();
+        assert_eq!(
+            smallest_grouping(&numbers, u64::MAX),
+            Err(format!("{} is too large to tabulate", u64::MAX))
+        );
+        assert!(smallest_grouping(&numbers, u64::MAX / 4).is_err());
+        let many = vec![1; 200];
+        assert!(smallest_grouping(&many, 100_000_000).is_err());
+        assert_eq!(smallest_grouping(&many, 3), Ok(Some(vec![0, 1, 2])));
+    }
+}
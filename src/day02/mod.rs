@@ -0,0 +1,578 @@
+use itertools::Itertools;
+use serde::Deserialize;
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    iter,
+    ops::RangeInclusive,
+};
+
+use crate::{
+    parsing::{self, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day02 {
+    pub first_policy: Policy,
+    pub second_policy: Policy,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Day02 {
+            first_policy: Policy::Range,
+            second_policy: Policy::ExactlyOnePosition,
+        }
+    }
+}
+
+impl Day for Day02 {
+    type Input = Vec<Entry>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, entries: &Self::Input) -> Answer {
+        count_valid(entries, &self.first_policy).into()
+    }
+
+    fn part_two(&self, entries: &Self::Input) -> Option<Answer> {
+        Some(count_valid(entries, &self.second_policy).into())
+    }
+
+    const REPORTS: &'static [&'static str] = &["audit"];
+
+    fn report(&self, entries: &Self::Input, name: &str) -> Option<String> {
+        match name {
+            "audit" => Some(
+                [
+                    ("first", &self.first_policy),
+                    ("second", &self.second_policy),
+                ]
+                .iter()
+                .map(|(part, policy)| {
+                    format!("{} policy ({})\n{}", part, policy, audit(entries, *policy))
+                })
+                .join("\n\n"),
+            ),
+            _ => None,
+        }
+    }
+}
+
+pub fn count_valid(entries: &[Entry], policy: &dyn PasswordPolicy) -> usize {
+    let correct_entries = entries.iter().filter(|entry| policy.is_valid(entry));
+    correct_entries.count()
+}
+
+/// Lists every entry with the verdict of the policy and the reason for it, followed by the count
+/// of valid entries.
+pub fn audit(entries: &[Entry], policy: &dyn PasswordPolicy) -> String {
+    let lines: Vec<_> = entries.iter().map(ToString::to_string).collect();
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut report = String::new();
+    for (entry, line) in entries.iter().zip(&lines) {
+        let verdict = if policy.is_valid(entry) {
+            "valid"
+        } else {
+            "invalid"
+        };
+        report += &format!(
+            "{:<7}  {:<width$}  {}\n",
+            verdict,
+            line,
+            policy.explain(entry),
+            width = width
+        );
+    }
+    report += &format!(
+        "{} of {} entries are valid",
+        count_valid(entries, policy),
+        entries.len()
+    );
+    report
+}
+
+fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parsing::lines_keeping_trailing_whitespace(input)
+        .map(parse_line)
+        .collect()
+}
+
+/// What the start of a line must look like, if it matches none of the forms.
+const POLICY_FORMS: &str = "a policy like `1-3 a: password` or `3 a: password`";
+
+/// Parses `N-M token: password` or `N token: password`, where the token is any text without
+/// whitespace or colons, and the password any printable text. Spaces at the end of the line are
+/// part of the password.
+fn parse_line(line: Line) -> Result<Entry, ParseError> {
+    let mut cursor = line.cursor();
+    if !cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+        return Err(cursor.error(POLICY_FORMS));
+    }
+    let first_number = cursor.number()?;
+    let second_number = if cursor.optional_tag("-") {
+        Some(cursor.number()?)
+    } else {
+        None
+    };
+    if !cursor.optional_tag(" ") {
+        let expected = match second_number {
+            Some(_) => "` ` followed by a letter or substring",
+            None => "`-` followed by a number, or ` ` followed by a letter or substring",
+        };
+        return Err(cursor.error(expected));
+    }
+    let token = cursor
+        .take_while1("a letter or substring", |c| {
+            !c.is_whitespace() && !c.is_control() && c != ':'
+        })?
+        .to_string();
+    cursor.tag(": ")?;
+    let password = cursor
+        .take_while1("a password", |c| !c.is_control())?
+        .to_string();
+    if !cursor.is_at_end() {
+        return Err(cursor.error("a printable character"));
+    }
+
+    Ok(Entry {
+        first_number,
+        second_number,
+        token,
+        password,
+    })
+}
+
+#[derive(Debug)]
+pub struct Entry {
+    first_number: usize,
+    /// `None` for policies with a single number, like `3 a: password`.
+    second_number: Option<usize>,
+    /// The policed letter, or a longer substring.
+    token: String,
+    password: String,
+}
+
+impl Entry {
+    /// The numbers of the policy, read as positions.
+    fn positions(&self) -> impl Iterator<Item = usize> {
+        iter::once(self.first_number).chain(self.second_number)
+    }
+
+    /// The numbers of the policy, read as a range. A single number is both ends.
+    fn range(&self) -> RangeInclusive<usize> {
+        self.first_number..=self.second_number.unwrap_or(self.first_number)
+    }
+
+    /// How often the token occurs in the password, counting overlapping occurrences. Comparing
+    /// bytes is enough, since a character of the token can't match in the middle of another one.
+    fn occurrences(&self) -> usize {
+        let password = self.password.as_bytes();
+        match *self.token.as_bytes() {
+            [letter] => password.iter().filter(|&&byte| byte == letter).count(),
+            ref token => password
+                .windows(token.len())
+                .filter(|&window| window == token)
+                .count(),
+        }
+    }
+
+    /// Whether the token starts at a position counted from 1.
+    fn has_token_at(&self, position: usize) -> bool {
+        let index = match position.checked_sub(1) {
+            Some(index) => index,
+            None => return false,
+        };
+        if self.password.is_ascii() {
+            return self.password.as_bytes()[index.min(self.password.len())..]
+                .starts_with(self.token.as_bytes());
+        }
+        self.password
+            .char_indices()
+            .nth(index)
+            .is_some_and(|(start, _)| self.password[start..].starts_with(&self.token))
+    }
+
+    /// As many characters as the token has at a position counted from 1, or `None` beyond the
+    /// end of the password.
+    fn at(&self, position: usize) -> Option<String> {
+        let index = position.checked_sub(1)?;
+        if index >= self.password.chars().count() {
+            return None;
+        }
+        let length = self.token.chars().count();
+        Some(self.password.chars().skip(index).take(length).collect())
+    }
+
+    fn describe_token(&self) -> String {
+        if self.token.chars().count() == 1 {
+            format!("letter '{}'", self.token)
+        } else {
+            format!("substring '{}'", self.token)
+        }
+    }
+}
+
+/// Writes the entry the way it is written in the input.
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.first_number)?;
+        if let Some(second_number) = self.second_number {
+            write!(f, "-{}", second_number)?;
+        }
+        write!(f, " {}: {}", self.token, self.password)
+    }
+}
+
+/// Decides whether the password of an entry is valid, interpreting the token and numbers of the
+/// entry in its own way.
+pub trait PasswordPolicy {
+    fn is_valid(&self, entry: &Entry) -> bool;
+    /// Why the entry is valid or not, like "letter 'b' occurs 0 times, required 1-3".
+    fn explain(&self, entry: &Entry) -> String;
+}
+
+/// The token occurs at least as often as the first number, and at most as often as the second.
+/// A single number has to be matched exactly.
+pub struct Range;
+
+impl PasswordPolicy for Range {
+    fn is_valid(&self, entry: &Entry) -> bool {
+        entry.range().contains(&entry.occurrences())
+    }
+
+    fn explain(&self, entry: &Entry) -> String {
+        let count = entry.occurrences();
+        let range = entry.range();
+        let required = if range.start() == range.end() {
+            range.start().to_string()
+        } else {
+            format!("{}-{}", range.start(), range.end())
+        };
+        format!(
+            "{} occurs {} {}, required {}",
+            entry.describe_token(),
+            count,
+            if count == 1 { "time" } else { "times" },
+            required
+        )
+    }
+}
+
+/// The token starts at exactly one of the positions, counted from 1.
+pub struct ExactlyOnePosition;
+
+impl PasswordPolicy for ExactlyOnePosition {
+    fn is_valid(&self, entry: &Entry) -> bool {
+        matching_positions(entry) == 1
+    }
+
+    fn explain(&self, entry: &Entry) -> String {
+        explain_positions(entry, "exactly 1")
+    }
+}
+
+/// The token starts at none of the positions.
+pub struct NoneOfPositions;
+
+impl PasswordPolicy for NoneOfPositions {
+    fn is_valid(&self, entry: &Entry) -> bool {
+        matching_positions(entry) == 0
+    }
+
+    fn explain(&self, entry: &Entry) -> String {
+        explain_positions(entry, "none")
+    }
+}
+
+/// The token starts at the given number of the positions or more.
+pub struct AtLeastPositions(pub usize);
+
+impl PasswordPolicy for AtLeastPositions {
+    fn is_valid(&self, entry: &Entry) -> bool {
+        matching_positions(entry) >= self.0
+    }
+
+    fn explain(&self, entry: &Entry) -> String {
+        explain_positions(entry, &format!("at least {}", self.0))
+    }
+}
+
+/// How many of the positions the token starts at. Positions outside of the password never match.
+fn matching_positions(entry: &Entry) -> usize {
+    entry
+        .positions()
+        .filter(|&position| entry.has_token_at(position))
+        .count()
+}
+
+fn explain_positions(entry: &Entry, required: &str) -> String {
+    let positions: Vec<_> = entry.positions().collect();
+    let details = positions
+        .iter()
+        .map(|&position| match entry.at(position) {
+            Some(text) => format!("position {} is '{}'", position, text),
+            None if position == 0 => "position 0 is invalid".to_string(),
+            None => format!(
+                "position {} beyond password length {}",
+                position,
+                entry.password.chars().count()
+            ),
+        })
+        .join(", ");
+    format!(
+        "{} at {} of {} {}, required {} ({})",
+        entry.describe_token(),
+        matching_positions(entry),
+        positions.len(),
+        if positions.len() == 1 {
+            "position"
+        } else {
+            "positions"
+        },
+        required,
+        details
+    )
+}
+
+/// No character occurs more often than the given number in a row, regardless of the letter and
+/// numbers of the entry.
+pub struct MaxConsecutiveRepeats(pub usize);
+
+impl MaxConsecutiveRepeats {
+    /// The character repeated most often in a row, and how often, preferring the first run.
+    fn longest_run(entry: &Entry) -> Option<(char, usize)> {
+        entry
+            .password
+            .chars()
+            .group_by(|&c| c)
+            .into_iter()
+            .map(|(c, run)| (c, run.count()))
+            .fold(None, |longest, (c, length)| match longest {
+                Some((_, longest_length)) if longest_length >= length => longest,
+                _ => Some((c, length)),
+            })
+    }
+}
+
+impl PasswordPolicy for MaxConsecutiveRepeats {
+    fn is_valid(&self, entry: &Entry) -> bool {
+        MaxConsecutiveRepeats::longest_run(entry).map_or(0, |(_, length)| length) <= self.0
+    }
+
+    fn explain(&self, entry: &Entry) -> String {
+        match MaxConsecutiveRepeats::longest_run(entry) {
+            Some((c, length)) => format!(
+                "'{}' repeats {} times in a row, allowed {}",
+                c, length, self.0
+            ),
+            None => "the password is empty".to_string(),
+        }
+    }
+}
+
+/// The policies that can be chosen in the parameters, deserialized from their names like
+/// `"range"`, followed by the number for policies that take one, like `"at-least-positions 2"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Policy {
+    Range,
+    ExactlyOnePosition,
+    NoneOfPositions,
+    AtLeastPositions(usize),
+    MaxConsecutiveRepeats(usize),
+}
+
+impl Policy {
+    /// Calls `f` with the policy this stands for.
+    fn with<T>(&self, f: impl FnOnce(&dyn PasswordPolicy) -> T) -> T {
+        match *self {
+            Policy::Range => f(&Range),
+            Policy::ExactlyOnePosition => f(&ExactlyOnePosition),
+            Policy::NoneOfPositions => f(&NoneOfPositions),
+            Policy::AtLeastPositions(count) => f(&AtLeastPositions(count)),
+            Policy::MaxConsecutiveRepeats(length) => f(&MaxConsecutiveRepeats(length)),
+        }
+    }
+}
+
+impl TryFrom<String> for Policy {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        let number = |argument: &str| {
+            argument
+                .parse()
+                .map_err(|_| format!("`{}` does not end with a number", name))
+        };
+        match name.split(' ').collect::<Vec<_>>()[..] {
+            ["range"] => Ok(Policy::Range),
+            ["exactly-one-position"] => Ok(Policy::ExactlyOnePosition),
+            ["none-of-positions"] => Ok(Policy::NoneOfPositions),
+            ["at-least-positions", count] => Ok(Policy::AtLeastPositions(number(count)?)),
+            ["max-consecutive-repeats", length] => {
+                Ok(Policy::MaxConsecutiveRepeats(number(length)?))
+            }
+            _ => Err(format!("`{}` is not a password policy", name)),
+        }
+    }
+}
+
+/// Writes the name the policy is chosen by.
+impl Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Policy::Range => f.write_str("range"),
+            Policy::ExactlyOnePosition => f.write_str("exactly-one-position"),
+            Policy::NoneOfPositions => f.write_str("none-of-positions"),
+            Policy::AtLeastPositions(count) => write!(f, "at-least-positions {}", count),
+            Policy::MaxConsecutiveRepeats(length) => {
+                write!(f, "max-consecutive-repeats {}", length)
+            }
+        }
+    }
+}
+
+impl PasswordPolicy for Policy {
+    fn is_valid(&self, entry: &Entry) -> bool {
+        self.with(|policy| policy.is_valid(entry))
+    }
+
+    fn explain(&self, entry: &Entry) -> String {
+        self.with(|policy| policy.explain(entry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEMO_INPUT: &str = "1-3 a: abcde
+    1-3 b: cdefg
+    2-9 c: ccccccccc";
+
+    #[test]
+    fn first_demo_solution() {
+        let solution = count_valid(&parse_input(DEMO_INPUT).unwrap(), &Range);
+        assert_eq!(solution, 2);
+    }
+
+    #[test]
+    fn second_demo_solution() {
+        let solution = count_valid(&parse_input(DEMO_INPUT).unwrap(), &ExactlyOnePosition);
+        assert_eq!(solution, 1);
+    }
+
+    #[test]
+    fn other_policies() {
+        let entries = parse_input(DEMO_INPUT).unwrap();
+        assert_eq!(count_valid(&entries, &NoneOfPositions), 1);
+        assert_eq!(count_valid(&entries, &AtLeastPositions(2)), 1);
+        assert_eq!(count_valid(&entries, &AtLeastPositions(1)), 2);
+        assert_eq!(count_valid(&entries, &MaxConsecutiveRepeats(3)), 2);
+    }
+
+    #[test]
+    fn explains_verdicts() {
+        let entries = parse_input(DEMO_INPUT).unwrap();
+        assert_eq!(
+            Range.explain(&entries[1]),
+            "letter 'b' occurs 0 times, required 1-3"
+        );
+        assert_eq!(
+            MaxConsecutiveRepeats(3).explain(&entries[2]),
+            "'c' repeats 9 times in a row, allowed 3"
+        );
+
+        let short = parse_input("1-9 a: abcde").unwrap();
+        assert!(ExactlyOnePosition.is_valid(&short[0]));
+        assert_eq!(
+            ExactlyOnePosition.explain(&short[0]),
+            "letter 'a' at 1 of 2 positions, required exactly 1 \
+             (position 1 is 'a', position 9 beyond password length 5)"
+        );
+
+        let zero = parse_input("0-2 b: abc").unwrap();
+        assert!(Policy::ExactlyOnePosition.is_valid(&zero[0]));
+        assert_eq!(
+            Policy::ExactlyOnePosition.explain(&zero[0]),
+            "letter 'b' at 1 of 2 positions, required exactly 1 \
+             (position 0 is invalid, position 2 is 'b')"
+        );
+
+        let report = audit(&entries, &Range);
+        assert!(report.starts_with("valid    1-3 a: abcde      letter 'a' occurs 1 time"));
+        assert!(report.ends_with("2 of 3 entries are valid"));
+    }
+
+    #[test]
+    fn accepts_substrings_and_single_numbers() {
+        let entries = parse_input("2-3 ab: abxab#ab\n1 ab: xabab\n1-2 é: aé b!").unwrap();
+        assert_eq!(entries[0].to_string(), "2-3 ab: abxab#ab");
+        assert_eq!(entries[1].to_string(), "1 ab: xabab");
+        assert_eq!(count_valid(&entries, &Range), 2);
+        assert_eq!(count_valid(&entries, &ExactlyOnePosition), 1);
+        assert_eq!(
+            Range.explain(&entries[1]),
+            "substring 'ab' occurs 2 times, required 1"
+        );
+        assert_eq!(
+            ExactlyOnePosition.explain(&entries[1]),
+            "substring 'ab' at 0 of 1 position, required exactly 1 (position 1 is 'xa')"
+        );
+    }
+
+    #[test]
+    fn keeps_spaces_around_passwords() {
+        let entries = parse_input("1 a: a \n1 a: a\n1 a:  a").unwrap();
+        assert_eq!(entries[0].password, "a ");
+        assert_eq!(entries[1].password, "a");
+        assert_eq!(entries[2].password, " a");
+        assert_eq!(count_valid(&entries, &ExactlyOnePosition), 2);
+        assert_eq!(parse_input("1 a: \t").unwrap_err().column, 6);
+    }
+
+    #[test]
+    fn reports_lines_of_no_form() {
+        let error = |line| {
+            let error = parse_input(line).unwrap_err();
+            (error.column, error.expected)
+        };
+        assert_eq!(error("a-3 a: abc"), (1, POLICY_FORMS.to_string()));
+        assert_eq!(
+            error("1+3 a: abc"),
+            (
+                2,
+                "`-` followed by a number, or ` ` followed by a letter or substring".to_string()
+            )
+        );
+        assert_eq!(error("1-3 : abc"), (5, "a letter or substring".to_string()));
+        assert_eq!(error("1-3 a abc"), (6, "`: `".to_string()));
+        assert_eq!(error("1-3 a:"), (6, "`: `".to_string()));
+        assert_eq!(
+            error("1-3 a: ab\u{7}c"),
+            (10, "a printable character".to_string())
+        );
+    }
+
+    #[test]
+    fn selects_policies_by_name() {
+        let day: Day02 = toml::from_str(
+            "first_policy = 'none-of-positions'\nsecond_policy = 'at-least-positions 2'",
+        )
+        .unwrap();
+        assert_eq!(day.first_policy, Policy::NoneOfPositions);
+        assert_eq!(day.second_policy, Policy::AtLeastPositions(2));
+        assert!(Policy::try_from("at-least-positions two".to_string()).is_err());
+        assert!(Policy::try_from("some-positions".to_string()).is_err());
+    }
+}
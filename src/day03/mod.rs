@@ -0,0 +1,322 @@
+use itertools::Itertools;
+use serde::Deserialize;
+use std::ops::RangeInclusive;
+
+use crate::{grid::Grid, parsing::ParseError, Answer, Day};
+
+/// A `(right, down)` step, where negative steps to the right go left.
+pub type Slope = (isize, usize);
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day03 {
+    /// The slope of the first part.
+    pub slope: Slope,
+    /// The slopes whose trees are multiplied in the second part.
+    pub slopes: Vec<Slope>,
+    /// The smallest and largest steps to the right tried by the `slopes` report.
+    pub search_right: (isize, isize),
+    /// The smallest and largest steps down tried by the `slopes` report.
+    pub search_down: (usize, usize),
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Day03 {
+            slope: (3, 1),
+            slopes: vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)],
+            search_right: (-7, 7),
+            search_down: (1, 2),
+        }
+    }
+}
+
+impl Day for Day03 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, map: &Self::Input) -> Answer {
+        let (right, down) = self.slope;
+        solve(map, right, down).into()
+    }
+
+    fn part_two(&self, map: &Self::Input) -> Option<Answer> {
+        let trees: usize = count_trees(map, &self.slopes).into_iter().product();
+        Some(trees.into())
+    }
+
+    const REPORTS: &'static [&'static str] = &["slopes", "path"];
+
+    fn report(&self, map: &Self::Input, name: &str) -> Option<String> {
+        match name {
+            "slopes" => {
+                let (min_right, max_right) = self.search_right;
+                let (min_down, max_down) = self.search_down;
+                Some(slope_table(&rank_slopes(
+                    map,
+                    min_right..=max_right,
+                    min_down..=max_down,
+                )))
+            }
+            "path" => Some(render_path(map, self.slope)),
+            _ => None,
+        }
+    }
+}
+
+/// The map with one bit per square, set for trees, and every row starting at a new word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    trees: Vec<u64>,
+}
+
+impl Map {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether there is a tree at a column inside of the map.
+    pub fn is_tree(&self, row: usize, column: usize) -> bool {
+        let word = self.trees[row * self.words_per_row + column / 64];
+        word >> (column % 64) & 1 == 1
+    }
+}
+
+impl From<&Grid<bool>> for Map {
+    fn from(grid: &Grid<bool>) -> Self {
+        let words_per_row = grid.width().div_ceil(64);
+        let mut trees = vec![0; words_per_row * grid.height()];
+        for ((row, column), &tree) in grid.positions().zip(grid.cells()) {
+            if tree {
+                trees[row * words_per_row + column / 64] |= 1 << (column % 64);
+            }
+        }
+        Map {
+            width: grid.width(),
+            height: grid.height(),
+            words_per_row,
+            trees,
+        }
+    }
+}
+
+/// Counts the trees on the way from the top left corner to the bottom of the map.
+fn solve(map: &Map, right: isize, down: usize) -> usize {
+    count_trees(map, &[(right, down)])[0]
+}
+
+/// Counts the trees of every slope in a single pass over the rows, in the order of the slopes.
+/// Slopes with 0 steps down only see the top left corner.
+pub fn count_trees(map: &Map, slopes: &[Slope]) -> Vec<usize> {
+    let steps_right: Vec<_> = slopes
+        .iter()
+        .map(|&(right, _)| right.rem_euclid(map.width as isize) as usize)
+        .collect();
+    let mut columns = vec![0; slopes.len()];
+    let mut trees = vec![0; slopes.len()];
+
+    for row in 0..map.height {
+        for (index, &(_, down)) in slopes.iter().enumerate() {
+            let on_row = if down == 0 { row == 0 } else { row % down == 0 };
+            if on_row {
+                if map.is_tree(row, columns[index]) {
+                    trees[index] += 1;
+                }
+                columns[index] = (columns[index] + steps_right[index]) % map.width;
+            }
+        }
+    }
+    trees
+}
+
+/// Draws the map with the path of a slope, marking the trees it hits `X` and the open squares it
+/// passes `O`. The map is repeated sideways as far as the path travels.
+pub fn render_path(map: &Map, (right, down): Slope) -> String {
+    let width = map.width as isize;
+    let path: Vec<_> = (0..map.height)
+        .map(|row| match down {
+            0 if row > 0 => None,
+            0 => Some(0),
+            _ if row % down != 0 => None,
+            _ => Some((row / down) as isize * right),
+        })
+        .collect();
+    let columns = path.iter().flatten();
+    let first = columns.clone().min().unwrap_or(&0).div_euclid(width) * width;
+    let last = (columns.max().unwrap_or(&0).div_euclid(width) + 1) * width;
+
+    (0..map.height)
+        .map(|row| {
+            (first..last)
+                .map(|column| {
+                    let tree = map.is_tree(row, column.rem_euclid(width) as usize);
+                    match (path[row] == Some(column), tree) {
+                        (true, true) => 'X',
+                        (true, false) => 'O',
+                        (false, true) => '#',
+                        (false, false) => '.',
+                    }
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+/// Counts the trees of every slope in the ranges, ordered from the fewest trees to the most.
+/// Steps of 0 down are skipped, since they never reach the bottom.
+pub fn rank_slopes(
+    map: &Map,
+    rights: RangeInclusive<isize>,
+    downs: RangeInclusive<usize>,
+) -> Vec<(Slope, usize)> {
+    let downs = (*downs.start()).max(1)..=*downs.end();
+    let slopes: Vec<_> = rights.cartesian_product(downs).collect();
+    let trees = count_trees(map, &slopes);
+    let mut ranked: Vec<_> = slopes.into_iter().zip(trees).collect();
+    ranked.sort_by_key(|&((right, down), trees)| (trees, right.abs(), down, right));
+    ranked
+}
+
+/// Names the slope with the fewest trees, followed by a table of all slopes.
+fn slope_table(ranked: &[(Slope, usize)]) -> String {
+    let ((right, down), trees) = match ranked.first() {
+        Some(&best) => best,
+        None => return "no slopes to try".to_string(),
+    };
+    let mut table = format!(
+        "fewest trees: right {}, down {} with {} trees\n\n{:>4}  {:>5}  {:>4}  {:>5}",
+        right, down, trees, "rank", "right", "down", "trees"
+    );
+    for (rank, ((right, down), trees)) in ranked.iter().enumerate() {
+        table += &format!("\n{:>4}  {:>5}  {:>4}  {:>5}", rank + 1, right, down, trees);
+    }
+    table
+}
+
+fn parse(input: &str) -> Result<Map, ParseError> {
+    let grid = Grid::parse(input, "`.` or `#`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(Map::from(&grid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEMO_INPUT: &str = "..##.......
+    #...#...#..
+    .#....#..#.
+    ..#.#...#.#
+    .#...##..#.
+    ..#.##.....
+    .#.#.#....#
+    .#........#
+    #.##...#...
+    #...##....#
+    .#..#...#.#";
+
+    #[test]
+    fn demo_solution_1() {
+        let solution = solve(&parse(DEMO_INPUT).unwrap(), 1, 1);
+        assert_eq!(solution, 2);
+    }
+    #[test]
+    fn demo_solution_2() {
+        let solution = solve(&parse(DEMO_INPUT).unwrap(), 3, 1);
+        assert_eq!(solution, 7);
+    }
+    #[test]
+    fn demo_solution_3() {
+        let solution = solve(&parse(DEMO_INPUT).unwrap(), 5, 1);
+        assert_eq!(solution, 3);
+    }
+    #[test]
+    fn demo_solution_4() {
+        let solution = solve(&parse(DEMO_INPUT).unwrap(), 7, 1);
+        assert_eq!(solution, 4);
+    }
+
+    #[test]
+    fn demo_solution_5() {
+        let solution = solve(&parse(DEMO_INPUT).unwrap(), 1, 2);
+        assert_eq!(solution, 2);
+    }
+
+    #[test]
+    fn ranks_slopes_including_leftward_ones() {
+        let map = parse(DEMO_INPUT).unwrap();
+        assert_eq!(solve(&map, -1, 1), 5);
+
+        let ranked = rank_slopes(&map, -2..=7, 0..=2);
+        assert_eq!(ranked.len(), 20);
+        assert!(ranked.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(ranked.contains(&((3, 1), 7)));
+        assert!(ranked.contains(&((-1, 1), 5)));
+        assert_eq!(ranked.first(), Some(&((5, 2), 0)));
+        assert!(slope_table(&ranked).starts_with("fewest trees: right 5, down 2 with 0 trees"));
+    }
+
+    #[test]
+    fn renders_the_path_like_the_puzzle() {
+        let map = parse(DEMO_INPUT).unwrap();
+        let expected = "O.##.........##.........##.......
+            #..O#...#..#...#...#..#...#...#..
+            .#....X..#..#....#..#..#....#..#.
+            ..#.#...#O#..#.#...#.#..#.#...#.#
+            .#...##..#..X...##..#..#...##..#.
+            ..#.##.......#.X#.......#.##.....
+            .#.#.#....#.#.#.#.O..#.#.#.#....#
+            .#........#.#........X.#........#
+            #.##...#...#.##...#...#.X#...#...
+            #...##....##...##....##...#X....#
+            .#..#...#.#.#..#...#.#.#..#...X.#";
+        assert_eq!(
+            render_path(&map, (3, 1)),
+            expected.lines().map(str::trim).join("\n")
+        );
+
+        let leftward = render_path(&map, (-1, 2));
+        let rows: Vec<_> = leftward.lines().collect();
+        assert_eq!(rows[0], "..##.......O.##.......");
+        assert_eq!(rows[1], "#...#...#..#...#...#..");
+        assert_eq!(rows[2], ".#....#..#O.#....#..#.");
+        assert_eq!(rows[10].find(['X', 'O']), Some(6));
+    }
+
+    #[test]
+    fn counts_many_slopes_on_a_wide_map() {
+        let mut state = 12345u64;
+        let cells = (0..150 * 300)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                state >> 61 == 0
+            })
+            .collect();
+        let grid = Grid::new(150, cells);
+        let map = Map::from(&grid);
+
+        let slopes: Vec<_> = (-200..=200).cartesian_product(1..=4).collect();
+        let trees = count_trees(&map, &slopes);
+        for (&(right, down), &count) in slopes.iter().zip(&trees) {
+            let expected = (0..grid.height())
+                .step_by(down)
+                .enumerate()
+                .filter(|&(step, row)| *grid.get_wrapping((row as isize, step as isize * right)))
+                .count();
+            assert_eq!(count, expected, "slope ({}, {})", right, down);
+        }
+    }
+}
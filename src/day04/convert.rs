@@ -0,0 +1,324 @@
+//! Passports in other formats than the batch file of the puzzle, so that they can be exchanged
+//! with other tools.
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use serde_json::Value;
+use std::fmt;
+
+use super::{Passport, KEY_VALUE_REGEX};
+use crate::parsing::{self, Line, ParseError};
+
+/// The formats passports are read from, chosen by their lowercase names.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Blank line separated passports, as in the puzzle.
+    #[default]
+    Batch,
+    /// One JSON object per line and passport, like `{"byr":"1937","hgt":"183cm"}`.
+    Json,
+    /// Rows of `passport,key,value` after that header, see [`to_csv`].
+    Csv,
+}
+
+/// Writes every passport on a line of its own, separated by blank lines, which
+/// [`super::parse`] reads back. Passports without fields are left out, since they would vanish
+/// between the blank lines anyway.
+pub fn to_batch(passports: &[Passport]) -> String {
+    passports
+        .iter()
+        .filter(|passport| !passport.fields.is_empty())
+        .map(|passport| {
+            let fields: Vec<_> = passport
+                .fields
+                .iter()
+                .map(|(key, value)| format!("{}:{}", key, value))
+                .collect();
+            fields.join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Writes a JSON object per passport, with the fields in their order. Passports without fields
+/// are left out, as in the other formats.
+pub fn to_json_lines(passports: &[Passport]) -> String {
+    let text = |text: &String| serde_json::to_string(text).expect("strings are valid JSON");
+    passports
+        .iter()
+        .filter(|passport| !passport.fields.is_empty())
+        .map(|passport| {
+            let fields: Vec<_> = passport
+                .fields
+                .iter()
+                .map(|(key, value)| format!("{}:{}", text(key), text(value)))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reads a JSON object per non-empty line. Values may be text or numbers, and every passport
+/// needs a field, so that it can be written as a batch again.
+pub fn from_json_lines(input: &str) -> Result<Vec<Passport>, ParseError> {
+    parsing::lines(input)
+        .map(|line| {
+            let fields: Fields = serde_json::from_str(line.text).map_err(|error| {
+                error_at_column(&line, error.column(), "a JSON object with text values")
+            })?;
+            if fields.0.is_empty() {
+                return Err(line.error_at(0, "a JSON object with at least one field"));
+            }
+            for (key, value) in &fields.0 {
+                check_field(key, value).map_err(|expected| line.error_at(0, expected))?;
+            }
+            Ok(Passport {
+                line: line.number,
+                fields: fields.0,
+                issues: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// The fields of a JSON object, in the order in which they are written.
+struct Fields(Vec<(String, String)>);
+
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+struct FieldsVisitor;
+
+impl<'de> Visitor<'de> for FieldsVisitor {
+    type Value = Fields;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object with text values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = Vec::new();
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            let value = match value {
+                Value::String(text) => text,
+                Value::Number(number) => number.to_string(),
+                _ => return Err(de::Error::custom(format!("`{}` is no text", key))),
+            };
+            fields.push((key, value));
+        }
+        Ok(Fields(fields))
+    }
+}
+
+const CSV_HEADER: [&str; 3] = ["passport", "key", "value"];
+
+/// Writes a row of `passport,key,value` per field, numbering the passports from 1. Unlike a
+/// column per key, this keeps the order and repeated keys of every passport. Passports without
+/// fields are left out.
+pub fn to_csv(passports: &[Passport]) -> String {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let write = || -> csv::Result<Vec<u8>> {
+        writer.write_record(CSV_HEADER)?;
+        for (index, passport) in passports.iter().enumerate() {
+            let number = (index + 1).to_string();
+            for (key, value) in &passport.fields {
+                writer.write_record([number.as_str(), key, value])?;
+            }
+        }
+        writer
+            .into_inner()
+            .map_err(|error| error.into_error().into())
+    };
+    let bytes = write().expect("writing to memory does not fail");
+    String::from_utf8(bytes).expect("the fields are text")
+}
+
+/// Reads rows of `passport,key,value` after that header. Consecutive rows of the same passport
+/// form a passport.
+pub fn from_csv(input: &str) -> Result<Vec<Passport>, ParseError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .trim(csv::Trim::All)
+        .from_reader(input.as_bytes());
+    let mut passports: Vec<Passport> = Vec::new();
+    let mut current = None;
+    for (index, record) in reader.records().enumerate() {
+        let error = |line: u64, expected: &str| ParseError {
+            line: line as usize,
+            column: 1,
+            expected: expected.to_string(),
+            section: None,
+        };
+        let record = record.map_err(|error_| {
+            let line = error_.position().map_or(1, |position| position.line());
+            error(line, "a row of `passport,key,value`")
+        })?;
+        let line = record.position().map_or(1, |position| position.line());
+        if index == 0 {
+            if record.iter().ne(CSV_HEADER.iter().copied()) {
+                return Err(error(line, "the header `passport,key,value`"));
+            }
+            continue;
+        }
+        let (number, key, value) = match (record.get(0), record.get(1), record.get(2)) {
+            (Some(number), Some(key), Some(value)) if record.len() == 3 => (number, key, value),
+            _ => return Err(error(line, "a row of `passport,key,value`")),
+        };
+        if current.as_deref() != Some(number) {
+            current = Some(number.to_string());
+            passports.push(Passport {
+                line: line as usize,
+                ..Passport::default()
+            });
+        }
+        check_field(key, value).map_err(|expected| error(line, &expected))?;
+        let passport = passports.last_mut().expect("a passport was started");
+        passport.fields.push((key.to_string(), value.to_string()));
+    }
+    Ok(passports)
+}
+
+/// Checks that a field can be written as `key:value` in a batch, and be read back as it is.
+fn check_field(key: &str, value: &str) -> Result<(), String> {
+    let text = format!("{}:{}", key, value);
+    if KEY_VALUE_REGEX
+        .find(&text)
+        .is_some_and(|field| field.as_str() == text)
+    {
+        Ok(())
+    } else if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphabetic()) {
+        Err(format!(
+            "letters, digits or `#` as the value of `{}` instead of `{}`",
+            key, value
+        ))
+    } else {
+        Err(format!("letters as the key instead of `{}`", key))
+    }
+}
+
+/// An error at a 1-based column of the trimmed text of a line.
+fn error_at_column(line: &Line, column: usize, expected: &str) -> ParseError {
+    let mut offset = column.saturating_sub(1).min(line.text.len());
+    while !line.text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    line.error_at(offset, expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day04::{parse, Schema, Tokens};
+
+    const BATCH: &str = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+    byr:1937 iyr:2017 cid:147 hgt:183cm
+
+    iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+    hcl:#cfa07d byr:1929 ecl:brn";
+
+    fn fields(passports: &[Passport]) -> Vec<Vec<(String, String)>> {
+        passports
+            .iter()
+            .map(|passport| passport.fields.clone())
+            .collect()
+    }
+
+    #[test]
+    fn round_trips_through_json_and_csv() {
+        let passports = parse(BATCH, &Schema::default(), Tokens::Lenient);
+
+        let json = to_json_lines(&passports);
+        assert!(
+            json.starts_with(r##"{"ecl":"gry","pid":"860033327","eyr":"2020","hcl":"#fffffd","##)
+        );
+        let from_json = from_json_lines(&json).unwrap();
+        assert_eq!(fields(&from_json), fields(&passports));
+
+        let csv = to_csv(&passports);
+        assert!(csv.starts_with("passport,key,value\n1,ecl,gry\n1,pid,860033327\n"));
+        let from_csv = from_csv(&csv).unwrap();
+        assert_eq!(fields(&from_csv), fields(&passports));
+        assert_eq!(from_csv[1].line, 10);
+
+        let batch = to_batch(&from_csv);
+        assert_eq!(
+            fields(&parse(&batch, &Schema::default(), Tokens::Lenient)),
+            fields(&passports)
+        );
+    }
+
+    #[test]
+    fn reports_malformed_imports() {
+        let json = from_json_lines("{\"byr\":1937,\"hgt\":\"183cm\"}\n{\"byr\":[1]}").unwrap_err();
+        assert_eq!(json.line, 2);
+        assert_eq!(
+            from_json_lines("{\"byr\":1937}").unwrap()[0].get("byr"),
+            Some("1937")
+        );
+
+        let header = from_csv("key,value\nbyr,1937").unwrap_err();
+        assert_eq!(
+            (header.line, header.expected.as_str()),
+            (1, "the header `passport,key,value`")
+        );
+        let row = from_csv("passport,key,value\n1,byr,1937\n2,hgt").unwrap_err();
+        assert_eq!(row.line, 3);
+    }
+
+    #[test]
+    fn imports_only_what_a_batch_can_hold() {
+        let json = "{\"byr\":1980,\"hcl\":\"#123abc\"}\n{\"ecl\":\"brn\"}";
+        let passports = from_json_lines(json).unwrap();
+        assert_eq!(to_batch(&passports), "byr:1980 hcl:#123abc\n\necl:brn");
+        let batch = parse(&to_batch(&passports), &Schema::default(), Tokens::Lenient);
+        assert_eq!(fields(&batch), fields(&passports));
+
+        let csv = "passport,key,value\n1,byr,1980\n2,ecl,brn\n2,hgt,170cm";
+        let passports = from_csv(csv).unwrap();
+        assert_eq!(to_batch(&passports), "byr:1980\n\necl:brn hgt:170cm");
+
+        let expected = |error: ParseError| (error.line, error.expected);
+        assert_eq!(
+            expected(from_json_lines("{\"byr\":\"1980\"}\n{\"byr\":\"19 80\"}").unwrap_err()),
+            (
+                2,
+                "letters, digits or `#` as the value of `byr` instead of `19 80`".to_string()
+            )
+        );
+        assert_eq!(
+            expected(from_json_lines("{}").unwrap_err()),
+            (1, "a JSON object with at least one field".to_string())
+        );
+        assert_eq!(
+            expected(from_csv("passport,key,value\n1,by:r,1980").unwrap_err()),
+            (2, "letters as the key instead of `by:r`".to_string())
+        );
+        assert_eq!(
+            expected(from_csv("passport,key,value\n1,hgt,\"170 cm\"").unwrap_err()),
+            (
+                2,
+                "letters, digits or `#` as the value of `hgt` instead of `170 cm`".to_string()
+            )
+        );
+        assert!(from_csv("passport,key,value\n1,hgt,").is_err());
+    }
+
+    #[test]
+    fn leaves_out_passports_without_fields() {
+        let passports = parse(
+            "byr:1980\n\n:5 junk\n\necl:brn",
+            &Schema::default(),
+            Tokens::Lenient,
+        );
+        assert_eq!(passports.len(), 3);
+        assert_eq!(to_batch(&passports), "byr:1980\n\necl:brn");
+        assert_eq!(to_json_lines(&passports).lines().count(), 2);
+    }
+}
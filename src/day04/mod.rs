@@ -0,0 +1,653 @@
+mod convert;
+
+pub use convert::{from_csv, from_json_lines, to_batch, to_csv, to_json_lines, Format};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{de, Deserialize, Deserializer};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    parsing::{self, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day04 {
+    /// The rules of the second part, read from the file at the given path. See `schema.toml`
+    /// next to this module for the format.
+    #[serde(deserialize_with = "schema_file")]
+    pub schema: Schema,
+    /// Rejects input with tokens that are no fields of the schema, empty values or repeated keys,
+    /// instead of skipping them.
+    pub strict: bool,
+    /// The format of the input, which is the puzzle's batch file unless it was converted.
+    pub format: Format,
+}
+
+impl Day for Day04 {
+    type Input = Vec<Passport>;
+
+    /// Passports are parsed leniently unless `strict` is set, skipping anything that is not a
+    /// field.
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let tokens = if self.strict {
+            Tokens::Strict
+        } else {
+            Tokens::Lenient
+        };
+        let passports = match self.format {
+            Format::Batch => parse(input, &self.schema, tokens),
+            Format::Json => return from_json_lines(input),
+            Format::Csv => return from_csv(input),
+        };
+        if self.strict {
+            let issues: Vec<_> = passports.iter().flat_map(|p| &p.issues).collect();
+            if let Some(&issue) = issues.first() {
+                let mut error = issue.clone();
+                if issues.len() > 1 {
+                    error.expected += &format!(
+                        " (1 of {} malformed tokens, see `aoc report 4 diagnostics`)",
+                        issues.len()
+                    );
+                }
+                return Err(error);
+            }
+        }
+        Ok(passports)
+    }
+
+    fn part_one(&self, passports: &Self::Input) -> Answer {
+        solve_presence(passports, &self.schema).into()
+    }
+
+    fn part_two(&self, passports: &Self::Input) -> Option<Answer> {
+        Some(solve_valids(passports, &self.schema).into())
+    }
+
+    const REPORTS: &'static [&'static str] = &["diagnostics", "batch", "json", "csv"];
+
+    fn report(&self, passports: &Self::Input, name: &str) -> Option<String> {
+        match name {
+            "diagnostics" => Some(diagnostics(passports, &self.schema, self.strict)),
+            "batch" => Some(to_batch(passports)),
+            "json" => Some(to_json_lines(passports)),
+            "csv" => Some(to_csv(passports).trim_end().to_string()),
+            _ => None,
+        }
+    }
+
+    /// The diagnostics list every token that a strict parser rejects, so they are checked even
+    /// if the passports are parsed leniently, and don't make strict parsing fail.
+    fn parse_for_report(&self, input: &str, name: &str) -> Result<Self::Input, ParseError> {
+        match (name, self.format) {
+            ("diagnostics", Format::Batch) => {
+                let tokens = if self.strict {
+                    Tokens::Strict
+                } else {
+                    Tokens::Checked
+                };
+                Ok(parse(input, &self.schema, tokens))
+            }
+            _ => self.parse(input),
+        }
+    }
+}
+
+fn solve_presence(passports: &[Passport], schema: &Schema) -> usize {
+    passports
+        .iter()
+        .filter(|passport| schema.required_present(passport))
+        .count()
+}
+
+fn solve_valids(passports: &[Passport], schema: &Schema) -> usize {
+    passports
+        .iter()
+        .filter(|passport| schema.is_valid(passport))
+        .count()
+}
+
+lazy_static! {
+    static ref KEY_VALUE_REGEX: Regex =
+        Regex::new(r"(?P<key>[a-zA-Z]+):(?P<value>[a-zA-Z0-9#]+)").unwrap();
+    static ref TOKEN_REGEX: Regex = Regex::new(r"\S+").unwrap();
+}
+
+/// Lists why every passport is invalid, followed by how often each reason occurred. The issues of
+/// the tokens are listed as skipped if the passports were parsed strictly, and as warnings
+/// otherwise, since the lenient parser may still have read a field from them.
+pub fn diagnostics(passports: &[Passport], schema: &Schema, strict: bool) -> String {
+    let issue_label = if strict { "skipped token" } else { "warning" };
+    let mut report = String::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for passport in passports {
+        let violations = schema.violations(passport);
+        let verdict = if violations.is_empty() {
+            "valid"
+        } else {
+            "invalid"
+        };
+        report += &format!("passport at line {}: {}\n", passport.line, verdict);
+        for violation in violations {
+            report += &format!("  {}\n", violation);
+            *counts.entry(violation.reason()).or_default() += 1;
+        }
+        for issue in &passport.issues {
+            report += &format!("  {}: {}\n", issue_label, issue);
+        }
+    }
+
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    report += &format!(
+        "\n{} of {} passports are valid",
+        solve_valids(passports, schema),
+        passports.len()
+    );
+    for (reason, count) in counts {
+        report += &format!("\n{:>5}  {}", count, reason);
+    }
+    report
+}
+
+/// How the tokens of the passports are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tokens {
+    /// Takes whatever looks like a field, without checking the tokens.
+    Lenient,
+    /// Takes whatever looks like a field, and records the tokens that a strict parser rejects
+    /// as issues.
+    Checked,
+    /// Skips the tokens that are no valid fields, and records them as issues.
+    Strict,
+}
+
+/// Parses the passports. The issues are only recorded if the tokens are checked or strict.
+fn parse(input: &str, schema: &Schema, tokens: Tokens) -> Vec<Passport> {
+    parsing::blocks(input)
+        .iter()
+        .map(|block| {
+            let (fields, issues) = match tokens {
+                Tokens::Strict => tokenize(block, schema),
+                Tokens::Checked => (lenient_fields(block), tokenize(block, schema).1),
+                Tokens::Lenient => (lenient_fields(block), Vec::new()),
+            };
+            Passport {
+                line: block[0].number,
+                fields,
+                issues,
+            }
+        })
+        .collect()
+}
+
+/// Whatever looks like a `key:value` field in a passport.
+fn lenient_fields(block: &[Line]) -> Vec<(String, String)> {
+    block
+        .iter()
+        .flat_map(|line| KEY_VALUE_REGEX.captures_iter(line.text))
+        .map(|cap| (cap["key"].to_string(), cap["value"].to_string()))
+        .collect()
+}
+
+/// Splits a passport into `key:value` tokens, and reports garbage tokens, empty values, keys
+/// that are not in the schema and keys that were given before.
+fn tokenize(block: &[Line], schema: &Schema) -> (Vec<(String, String)>, Vec<ParseError>) {
+    let mut fields = Vec::new();
+    let mut issues = Vec::new();
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for line in block {
+        for token in TOKEN_REGEX.find_iter(line.text) {
+            let start = token.start();
+            let issue = match token.as_str().split_once(':') {
+                None => Some(line.error_at(start, "a field like `key:value`")),
+                Some(("", _)) => Some(line.error_at(start, "a key before `:`")),
+                Some((key, "")) => {
+                    Some(line.error_at(start + key.len() + 1, format!("a value for `{}`", key)))
+                }
+                Some((key, _)) if !schema.fields.iter().any(|field| field.key == key) => {
+                    Some(line.error_at(start, format!("a key of the schema instead of `{}`", key)))
+                }
+                Some((key, _)) if seen.contains_key(key) => Some(line.error_at(
+                    start,
+                    format!("`{}` only once (first given on line {})", key, seen[key]),
+                )),
+                Some((key, value)) => {
+                    seen.insert(key, line.number);
+                    fields.push((key.to_string(), value.to_string()));
+                    None
+                }
+            };
+            issues.extend(issue);
+        }
+    }
+    (fields, issues)
+}
+
+/// The fields of a passport, in the order of the input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Passport {
+    /// The line the passport starts on, counted from 1.
+    pub line: usize,
+    pub fields: Vec<(String, String)>,
+    /// The tokens that are no valid fields, in the order of the input.
+    pub issues: Vec<ParseError>,
+}
+
+impl Passport {
+    /// The value of a field. If the key is repeated, the last value counts.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .rev()
+            .find(|(field, _)| field == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+}
+
+/// The fields a passport should have, and what their values must look like.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    /// Reads a schema from a TOML file.
+    pub fn load(path: &Path) -> Result<Schema, String> {
+        let document = fs::read_to_string(path)
+            .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+        Schema::parse(&document)
+            .map_err(|error| format!("invalid schema in {}: {}", path.display(), error))
+    }
+
+    pub fn parse(document: &str) -> Result<Schema, String> {
+        toml::from_str(document).map_err(|error| error.to_string())
+    }
+
+    /// Whether all required fields are there, regardless of their values.
+    pub fn required_present(&self, passport: &Passport) -> bool {
+        self.fields
+            .iter()
+            .filter(|field| field.required)
+            .all(|field| passport.contains_key(&field.key))
+    }
+
+    /// Whether all required fields are there, and all fields of the schema have valid values.
+    pub fn is_valid(&self, passport: &Passport) -> bool {
+        self.fields
+            .iter()
+            .all(|field| match passport.get(&field.key) {
+                Some(value) => field.rule.accepts(value),
+                None => !field.required,
+            })
+    }
+
+    /// Every missing required field and invalid value, in the order of the schema.
+    pub fn violations(&self, passport: &Passport) -> Vec<Violation> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let (kind, message) = match passport.get(&field.key) {
+                    Some(value) => field.rule.check(value).err()?,
+                    None if field.required => ("missing", String::new()),
+                    None => return None,
+                };
+                Some(Violation {
+                    key: field.key.clone(),
+                    kind,
+                    message,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The rules of the puzzle.
+impl Default for Schema {
+    fn default() -> Self {
+        Schema::parse(include_str!("schema.toml")).expect("the puzzle's schema is valid")
+    }
+}
+
+fn schema_file<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Schema, D::Error> {
+    let path = PathBuf::deserialize(deserializer)?;
+    Schema::load(&path).map_err(de::Error::custom)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Field {
+    pub key: String,
+    #[serde(default = "required_by_default")]
+    pub required: bool,
+    #[serde(flatten)]
+    pub rule: Rule,
+}
+
+fn required_by_default() -> bool {
+    true
+}
+
+/// What the value of a field must look like.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum Rule {
+    Integer { min: u64, max: u64 },
+    Measure { units: Vec<Unit> },
+    Regex { pattern: Pattern },
+    Enum { values: Vec<String> },
+    Any,
+}
+
+impl Rule {
+    pub fn accepts(&self, value: &str) -> bool {
+        self.check(value).is_ok()
+    }
+
+    /// The kind of the problem with a value, like "outside range", and a description of it, like
+    /// "190in outside 59-76".
+    pub fn check(&self, value: &str) -> Result<(), (&'static str, String)> {
+        let in_range = |number: &str, min: u64, max: u64| match number.parse::<u64>() {
+            Ok(number) if (min..=max).contains(&number) => Ok(()),
+            Ok(_) => Err((
+                "outside range",
+                format!("{} outside {}-{}", value, min, max),
+            )),
+            Err(_) => Err(("not a number", format!("{} is not a number", value))),
+        };
+        match self {
+            Rule::Integer { min, max } => in_range(value, *min, *max),
+            Rule::Measure { units } => units
+                .iter()
+                .find_map(|unit| {
+                    let number = value.strip_suffix(&unit.unit)?;
+                    Some(in_range(number, unit.min, unit.max))
+                })
+                .unwrap_or_else(|| {
+                    let units: Vec<_> = units.iter().map(|unit| unit.unit.as_str()).collect();
+                    Err((
+                        "unknown unit",
+                        format!("{} has none of the units {}", value, units.join(", ")),
+                    ))
+                }),
+            Rule::Regex { pattern } if pattern.0.is_match(value) => Ok(()),
+            Rule::Regex { pattern } => Err((
+                "no match",
+                format!("{} does not match `{}`", value, pattern.0),
+            )),
+            Rule::Enum { values } if values.iter().any(|valid| valid == value) => Ok(()),
+            Rule::Enum { values } => Err((
+                "not allowed",
+                format!("{} is not one of {}", value, values.join(", ")),
+            )),
+            Rule::Any => Ok(()),
+        }
+    }
+}
+
+/// A missing required field or an invalid value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub key: String,
+    /// What is wrong, like "missing" or "outside range".
+    pub kind: &'static str,
+    /// The problem with the value, like "190in outside 59-76", or empty if the field is missing.
+    pub message: String,
+}
+
+impl Violation {
+    /// Groups violations of the same field and kind, like "missing hgt" or "hgt outside range".
+    pub fn reason(&self) -> String {
+        match self.kind {
+            "missing" => format!("missing {}", self.key),
+            kind => format!("{} {}", self.key, kind),
+        }
+    }
+}
+
+/// Writes the violation like "missing hgt" or "hgt 190in outside 59-76".
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            "missing" => write!(f, "missing {}", self.key),
+            _ => write!(f, "{} {}", self.key, self.message),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Unit {
+    pub unit: String,
+    pub min: u64,
+    pub max: u64,
+}
+
+/// A regular expression that has to match somewhere in a value, deserialized from its source.
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Pattern(Regex);
+
+impl TryFrom<String> for Pattern {
+    type Error = String;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        Regex::new(&source)
+            .map(Pattern)
+            .map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solver;
+
+    const DEMO_INPUT: &str = "
+    ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+    byr:1937 iyr:2017 cid:147 hgt:183cm
+    
+    iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+    hcl:#cfa07d byr:1929
+    
+    hcl:#ae17e1 iyr:2013
+    eyr:2024
+    ecl:brn pid:760753108 byr:1931
+    hgt:179cm
+    
+    hcl:#cfa07d eyr:2025 pid:166559648
+    iyr:2011 ecl:brn hgt:59in";
+
+    #[test]
+    fn demo_solution_1() {
+        let solution = solve_presence(
+            &parse(DEMO_INPUT, &Schema::default(), Tokens::Lenient),
+            &Schema::default(),
+        );
+        assert_eq!(solution, 2);
+    }
+
+    #[test]
+    fn demo_solution_valids() {
+        let valid_inputs = "
+        pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+        hcl:#623a2f
+
+        eyr:2029 ecl:blu cid:129 byr:1989
+        iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+        hcl:#888785
+        hgt:164cm byr:2001 iyr:2015 cid:88
+        pid:545766238 ecl:hzl
+        eyr:2022
+
+        iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+        ";
+
+        let solution = solve_valids(
+            &parse(valid_inputs, &Schema::default(), Tokens::Lenient),
+            &Schema::default(),
+        );
+        assert_eq!(solution, 4);
+    }
+
+    #[test]
+    fn demo_solution_invalids() {
+        let invalid_inputs = "
+        eyr:1972 cid:100
+        hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+        iyr:2019
+        hcl:#602927 eyr:1967 hgt:170cm
+        ecl:grn pid:012533040 byr:1946
+
+        hcl:dab227 iyr:2012
+        ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+        hgt:59cm ecl:zzz
+        eyr:2038 hcl:74454a iyr:2023
+        pid:3556412378 byr:2007
+
+        byr:2001
+        iyr:2015
+        eyr:2022
+        hgt:180cma
+        hcl:#888785
+        ecl:hzl
+        pid:545766238
+        cid:88
+        ";
+
+        let solution = solve_valids(
+            &parse(invalid_inputs, &Schema::default(), Tokens::Lenient),
+            &Schema::default(),
+        );
+        assert_eq!(solution, 0);
+    }
+
+    #[test]
+    fn reads_rules_from_a_schema() {
+        let schema = Schema::parse(
+            "[[fields]]
+            key = 'hgt'
+            type = 'measure'
+            units = [{ unit = 'm', min = 1, max = 2 }]
+
+            [[fields]]
+            key = 'ecl'
+            type = 'enum'
+            values = ['red']
+            required = false",
+        )
+        .unwrap();
+        let passports = parse(
+            "hgt:2m\n\nhgt:2m ecl:blu\n\nhgt:3m\n\necl:red",
+            &schema,
+            Tokens::Lenient,
+        );
+        let valid: Vec<_> = passports.iter().map(|p| schema.is_valid(p)).collect();
+        assert_eq!(valid, vec![true, false, false, false]);
+        assert_eq!(solve_presence(&passports, &schema), 3);
+
+        assert!(Schema::parse("[[fields]]\nkey = 'x'\ntype = 'colour'").is_err());
+        assert!(Schema::parse("[[fields]]\nkey = 'x'\ntype = 'regex'\npattern = '('").is_err());
+    }
+
+    #[test]
+    fn diagnoses_invalid_passports() {
+        let input = "hgt:190in byr:1919 ecl:zzz iyr:2015
+        eyr:2022 hcl:#123abc
+
+        pid:012345678 hgt:170 byr:1980 iyr:2012 eyr:2025 hcl:#123abc ecl:amb";
+        let schema = Schema::default();
+        let passports = parse(input, &schema, Tokens::Lenient);
+        assert_eq!(passports[1].line, 4);
+
+        let violations: Vec<_> = schema
+            .violations(&passports[0])
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                "byr 1919 outside 1920-2002",
+                "hgt 190in outside 59-76",
+                "ecl zzz is not one of amb, blu, brn, gry, grn, hzl, oth",
+                "missing pid",
+            ]
+        );
+
+        let report = diagnostics(&passports, &schema, false);
+        assert!(
+            report.contains("passport at line 4: invalid\n  hgt 170 has none of the units cm, in")
+        );
+        assert!(report.ends_with("0 of 2 passports are valid\n    1  byr outside range\n    1  ecl not allowed\n    1  hgt outside range\n    1  hgt unknown unit\n    1  missing pid"));
+    }
+
+    #[test]
+    fn strict_parsing_reports_malformed_tokens() {
+        let input = "byr:1980 iyr: junk hgt:170cm
+        byr:1990 xyz:1 :5 hcl:#123abc";
+        let strict_day = Day04 {
+            strict: true,
+            ..Day04::default()
+        };
+        let error = strict_day.parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 14));
+        assert_eq!(
+            error.expected,
+            "a value for `iyr` (1 of 5 malformed tokens, see `aoc report 4 diagnostics`)"
+        );
+        let error = strict_day.parse("byr:1980\n\niyr:").unwrap_err();
+        assert_eq!(error.expected, "a value for `iyr`");
+
+        let day = Day04::default();
+        assert!(day.parse(input).unwrap()[0].issues.is_empty());
+        let passports = day.parse_for_report(input, "diagnostics").unwrap();
+        let issues: Vec<_> = passports[0]
+            .issues
+            .iter()
+            .map(|issue| (issue.line, issue.column, issue.expected.as_str()))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (1, 14, "a value for `iyr`"),
+                (1, 15, "a field like `key:value`"),
+                (2, 9, "`byr` only once (first given on line 1)"),
+                (2, 18, "a key of the schema instead of `xyz`"),
+                (2, 24, "a key before `:`"),
+            ]
+        );
+        assert_eq!(passports[0].get("byr"), Some("1990"));
+        assert_eq!(passports[0].get("xyz"), Some("1"));
+
+        let strict = strict_day.parse_for_report(input, "diagnostics").unwrap();
+        assert_eq!(strict[0].get("byr"), Some("1980"));
+        assert_eq!(strict[0].get("xyz"), None);
+        assert_eq!(strict[0].issues, passports[0].issues);
+
+        let warning =
+            "  warning: expected a key of the schema instead of `xyz` at line 2, column 18\n";
+        assert!(diagnostics(&passports, &Schema::default(), false).contains(warning));
+        let report = Solver::report(&strict_day, input, "diagnostics")
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.matches("  skipped token: ").count(), 5);
+        let skipped = "  skipped token: expected a key of the schema instead of `xyz` at line 2";
+        assert!(report.contains(skipped));
+    }
+}
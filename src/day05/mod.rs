@@ -0,0 +1,280 @@
+use serde::Deserialize;
+use std::convert::TryFrom;
+
+use crate::{
+    parsing::{self, Cursor, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(try_from = "Geometry")]
+pub struct Day05 {
+    pub geometry: Geometry,
+}
+
+impl TryFrom<Geometry> for Day05 {
+    type Error = String;
+
+    fn try_from(geometry: Geometry) -> Result<Self, Self::Error> {
+        let bits = geometry.row_bits.checked_add(geometry.column_bits);
+        if bits.is_none_or(|bits| bits >= usize::BITS) {
+            return Err(format!(
+                "rows and columns need fewer than {} bits together",
+                usize::BITS
+            ));
+        }
+        for [low, high] in [geometry.row_letters, geometry.column_letters] {
+            if low == high {
+                return Err(format!("`{}` can't stand for both halves", low));
+            }
+        }
+        Ok(Day05 { geometry })
+    }
+}
+
+impl Day for Day05 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        self.geometry.parse_boarding_passes(input)
+    }
+
+    fn part_one(&self, seats: &Self::Input) -> Answer {
+        match solve_first(seats) {
+            Some(seat) => seat.into(),
+            None => Answer::Missing("no boarding passes".to_string()),
+        }
+    }
+
+    fn part_two(&self, seats: &Self::Input) -> Option<Answer> {
+        Some(match solve_second(seats) {
+            Some(seat) => seat.into(),
+            None => Answer::Missing("no free seat between two taken ones".to_string()),
+        })
+    }
+}
+
+fn solve_first(seats: &[usize]) -> Option<usize> {
+    seats.iter().max().copied()
+}
+
+/// The free seat whose neighbours on both sides are taken.
+fn solve_second(seats: &[usize]) -> Option<usize> {
+    let mut taken_seats = seats.to_vec();
+    taken_seats.sort_unstable();
+
+    taken_seats
+        .windows(2)
+        .find(|pair| pair[1] == pair[0] + 2)
+        .map(|pair| pair[0] + 1)
+}
+
+/// The layout of the plane, and how boarding passes describe it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Geometry {
+    /// How many letters choose the row, so that there are `2^row_bits` rows.
+    pub row_bits: u32,
+    /// How many letters choose the column, so that there are `2^column_bits` columns.
+    pub column_bits: u32,
+    /// The letters for the front and back half of the remaining rows.
+    pub row_letters: [char; 2],
+    /// The letters for the left and right half of the remaining columns.
+    pub column_letters: [char; 2],
+}
+
+/// The plane of the puzzle, with 128 rows of 8 seats.
+impl Default for Geometry {
+    fn default() -> Self {
+        Geometry {
+            row_bits: 7,
+            column_bits: 3,
+            row_letters: ['F', 'B'],
+            column_letters: ['L', 'R'],
+        }
+    }
+}
+
+impl Geometry {
+    fn parse_boarding_passes(&self, input: &str) -> Result<Vec<usize>, ParseError> {
+        let seats: Vec<_> = parsing::lines(input)
+            .map(|pass| {
+                let (row, column) = self.parse_seat(pass)?;
+                Ok(self.seat_id(row, column))
+            })
+            .collect::<Result<_, _>>()?;
+        if seats.is_empty() {
+            return Err(parsing::end_of_input(input, "a boarding pass"));
+        }
+        Ok(seats)
+    }
+
+    fn parse_seat(&self, pass: Line) -> Result<(usize, usize), ParseError> {
+        let mut cursor = pass.cursor();
+        let row = parse_bits(&mut cursor, self.row_bits, self.row_letters)?;
+        let column = parse_bits(&mut cursor, self.column_bits, self.column_letters)?;
+        cursor.end()?;
+
+        Ok((row, column))
+    }
+
+    /// The row times the number of columns, plus the column.
+    pub fn seat_id(&self, row: usize, column: usize) -> usize {
+        row << self.column_bits | column
+    }
+
+    /// The row and column of a single boarding pass, like `FBFBBFFRLR`.
+    pub fn decode_seat(&self, code: &str) -> Result<(usize, usize), ParseError> {
+        let mut lines = parsing::lines(code);
+        let [low, high] = self.row_letters;
+        let pass = lines
+            .next()
+            .ok_or_else(|| parsing::end_of_input(code, format!("`{}` or `{}`", low, high)))?;
+        if let Some(extra) = lines.next() {
+            return Err(extra.error_at(0, "a single boarding pass"));
+        }
+        self.parse_seat(pass)
+    }
+
+    /// The boarding pass of a seat, or `None` if the seat is not in the plane.
+    pub fn encode_seat(&self, row: usize, column: usize) -> Option<String> {
+        if row >> self.row_bits != 0 || column >> self.column_bits != 0 {
+            return None;
+        }
+        let mut code = encode_bits(row, self.row_bits, self.row_letters);
+        code.push_str(&encode_bits(column, self.column_bits, self.column_letters));
+        Some(code)
+    }
+
+    /// The boarding pass of a seat id, or `None` if the seat is not in the plane.
+    pub fn encode_seat_id(&self, id: usize) -> Option<String> {
+        let columns = 1 << self.column_bits;
+        self.encode_seat(id / columns, id % columns)
+    }
+}
+
+/// Reads `count` letters as a binary number, from the highest bit to the lowest.
+fn parse_bits(
+    cursor: &mut Cursor,
+    count: u32,
+    [low, high]: [char; 2],
+) -> Result<usize, ParseError> {
+    let expected = format!("`{}` or `{}`", low, high);
+    (0..count).try_fold(0, |number, _| {
+        let bit = cursor.char(&expected, |c| match c {
+            c if c == low => Some(0),
+            c if c == high => Some(1),
+            _ => None,
+        })?;
+        Ok(number << 1 | bit)
+    })
+}
+
+/// Writes the lowest `count` bits of the number from the highest to the lowest, using the
+/// letters for low and high bits.
+fn encode_bits(number: usize, count: u32, [low, high]: [char; 2]) -> String {
+    (0..count)
+        .rev()
+        .map(|bit| if number >> bit & 1 == 1 { high } else { low })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_solution_1() {
+        let demo_input = "FBFBBFFRLR";
+        let solution = Geometry::default()
+            .parse_boarding_passes(demo_input)
+            .unwrap();
+        assert_eq!(solution, vec![357]);
+    }
+
+    #[test]
+    fn demo_solution_2() {
+        let demo_input = "BFFFBBFRRR";
+        let solution = Geometry::default()
+            .parse_boarding_passes(demo_input)
+            .unwrap();
+        assert_eq!(solution, vec![567]);
+    }
+    #[test]
+    fn demo_solution_3() {
+        let demo_input = "FFFBBBFRRR";
+        let solution = Geometry::default()
+            .parse_boarding_passes(demo_input)
+            .unwrap();
+        assert_eq!(solution, vec![119]);
+    }
+    #[test]
+    fn demo_solution_4() {
+        let demo_input = "BBFFBBFRLL";
+        let solution = Geometry::default()
+            .parse_boarding_passes(demo_input)
+            .unwrap();
+        assert_eq!(solution, vec![820]);
+    }
+
+    #[test]
+    fn encodes_seats() {
+        let geometry = Geometry::default();
+        assert_eq!(geometry.encode_seat(44, 5).as_deref(), Some("FBFBBFFRLR"));
+        assert_eq!(geometry.encode_seat_id(820).as_deref(), Some("BBFFBBFRLL"));
+        assert_eq!(geometry.encode_seat(128, 0), None);
+        assert_eq!(geometry.encode_seat(0, 8), None);
+        assert_eq!(geometry.encode_seat_id(1024), None);
+    }
+
+    fn assert_round_trips(geometry: &Geometry) {
+        for id in 0..1 << (geometry.row_bits + geometry.column_bits) {
+            let code = geometry.encode_seat_id(id).unwrap();
+            let (row, column) = geometry.decode_seat(&code).unwrap();
+            assert_eq!(geometry.seat_id(row, column), id, "{}", code);
+            assert_eq!(geometry.encode_seat(row, column), Some(code));
+        }
+    }
+
+    #[test]
+    fn encoding_round_trips_for_every_seat() {
+        assert_round_trips(&Geometry::default());
+    }
+
+    #[test]
+    fn supports_other_planes() {
+        let day: Day05 = toml::from_str(
+            "row_bits = 5\ncolumn_bits = 2\nrow_letters = ['0', '1']\ncolumn_letters = ['a', 'b']",
+        )
+        .unwrap();
+        let geometry = &day.geometry;
+        assert_eq!(geometry.decode_seat("00101ba"), Ok((5, 2)));
+        assert_eq!(geometry.parse_boarding_passes("00101ba"), Ok(vec![22]));
+        assert_eq!(geometry.encode_seat(32, 0), None);
+        assert_round_trips(geometry);
+
+        let error = geometry.decode_seat("0010ba").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (5, "`0` or `1`"));
+
+        assert!(toml::from_str::<Day05>("row_letters = ['F', 'F']").is_err());
+        assert!(toml::from_str::<Day05>("row_bits = 40\ncolumn_bits = 30").is_err());
+        assert!(toml::from_str::<Day05>("rows = 7").is_err());
+    }
+
+    #[test]
+    fn finds_free_seats_in_tiny_planes() {
+        let day: Day05 = toml::from_str("row_bits = 1\ncolumn_bits = 1").unwrap();
+        let full = day.parse("FL\nFR\nBL").unwrap();
+        assert_eq!(day.part_one(&full), Answer::Number(2));
+        assert_eq!(
+            day.part_two(&full),
+            Some(Answer::Missing(
+                "no free seat between two taken ones".to_string()
+            ))
+        );
+        assert_eq!(solve_second(&day.parse("BL\nFL").unwrap()), Some(1));
+        assert_eq!(solve_second(&day.parse("FL\nBR").unwrap()), None);
+        assert_eq!(solve_second(&[0]), None);
+        assert_eq!(solve_first(&[]), None);
+    }
+}
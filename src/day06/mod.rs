@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use crate::{
+    parsing::{self, ParseError},
+    Answer, Day,
+};
+
+#[derive(Default)]
+pub struct Day06;
+
+impl Day for Day06 {
+    type Input = Vec<Group>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, groups: &Self::Input) -> Answer {
+        solve_first(groups).into()
+    }
+
+    fn part_two(&self, groups: &Self::Input) -> Option<Answer> {
+        Some(solve_second(groups).into())
+    }
+}
+
+fn solve_first(groups: &[Group]) -> usize {
+    groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .fold(HashSet::new(), |acc, next| {
+                    acc.union(next).cloned().collect()
+                })
+                .len()
+        })
+        .sum()
+}
+
+fn solve_second(groups: &[Group]) -> usize {
+    groups
+        .iter()
+        .map(|group| {
+            let mut people = group.iter();
+            let first = people.next().unwrap().clone();
+            people
+                .fold(first, |acc, next| acc.intersection(next).cloned().collect())
+                .len()
+        })
+        .sum()
+}
+
+fn parse(input: &str) -> Result<Vec<Group>, ParseError> {
+    parsing::blocks(input)
+        .into_iter()
+        .map(|group_lines| {
+            group_lines
+                .into_iter()
+                .map(|line| {
+                    let mut cursor = line.cursor();
+                    let answers = cursor.take_while(|c| c.is_ascii_lowercase());
+                    cursor
+                        .end()
+                        .map_err(|_| cursor.error("a question from `a` to `z`"))?;
+                    Ok(answers.chars().collect())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The answers of each person in a group.
+pub type Group = Vec<HashSet<char>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_solution_1() {
+        let demo_input = "abc
+
+        a
+        b
+        c
+        
+        ab
+        ac
+        
+        a
+        a
+        a
+        a
+        
+        b";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 11);
+    }
+}
@@ -0,0 +1,211 @@
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
+
+use crate::{
+    parsing::{self, Cursor, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day07 {
+    /// The bag both parts ask about.
+    pub bag: Bag,
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        Day07 {
+            bag: Bag::from("shiny", "gold"),
+        }
+    }
+}
+
+impl Day for Day07 {
+    type Input = Dependencies;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_dependencies(input)
+    }
+
+    fn part_one(&self, dependencies: &Self::Input) -> Answer {
+        solve_first(dependencies, &self.bag).into()
+    }
+
+    fn part_two(&self, dependencies: &Self::Input) -> Option<Answer> {
+        Some(solve_second(dependencies, &self.bag).into())
+    }
+}
+
+fn solve_first(bag_dependencies: &Dependencies, target: &Bag) -> usize {
+    let mut inverse_deps = HashMap::new();
+    bag_dependencies.iter().for_each(|(bag, deps)| {
+        deps.iter().for_each(|(_, dep)| {
+            inverse_deps
+                .entry(dep)
+                .or_insert(HashSet::new())
+                .insert(bag);
+        });
+    });
+
+    let mut result = HashSet::new();
+    let mut to_visit = inverse_deps.get(target).cloned().unwrap_or_default();
+
+    while !to_visit.is_empty() {
+        result = result.union(&to_visit).cloned().collect();
+
+        let mut to_visit_next = HashSet::new();
+        to_visit.clone().iter().for_each(|node| {
+            if let Some(deps) = inverse_deps.get(node) {
+                to_visit_next = to_visit_next.union(deps).cloned().collect();
+            }
+        });
+        to_visit = to_visit_next;
+    }
+
+    result.len()
+}
+
+fn solve_second(bag_dependencies: &Dependencies, target: &Bag) -> usize {
+    bag_dependencies
+        .get(target)
+        .into_iter()
+        .flatten()
+        .map(|(count, dep)| count + (count * count_deps(dep, bag_dependencies)))
+        .sum()
+}
+
+fn count_deps(target: &Bag, deps: &Dependencies) -> usize {
+    deps.get(target)
+        .map(|d| {
+            d.iter()
+                .map(|(count, dep)| count + (count * count_deps(dep, deps)))
+                .sum()
+        })
+        .unwrap_or(1)
+}
+
+fn parse_dependencies(input: &str) -> Result<Dependencies, ParseError> {
+    parsing::lines(input).map(parse_line).collect()
+}
+
+fn parse_line(line: Line) -> Result<(Bag, Vec<(usize, Bag)>), ParseError> {
+    let mut cursor = line.cursor();
+    let first_bag = parse_bag(&mut cursor)?;
+    cursor.tag(" bags contain ")?;
+
+    let mut other_bags = Vec::new();
+    if !cursor.optional_tag("no other bags") {
+        loop {
+            let count = cursor.number()?;
+            cursor.tag(" ")?;
+            let bag = parse_bag(&mut cursor)?;
+            cursor.tag(" bag")?;
+            cursor.optional_tag("s");
+            other_bags.push((count, bag));
+
+            if !cursor.optional_tag(", ") {
+                break;
+            }
+        }
+    }
+    cursor.tag(".")?;
+    cursor.end()?;
+
+    Ok((first_bag, other_bags))
+}
+
+fn parse_bag(cursor: &mut Cursor) -> Result<Bag, ParseError> {
+    let adjective = cursor.take_while1("an adjective", |c| c.is_ascii_lowercase())?;
+    cursor.tag(" ")?;
+    let color = cursor.take_while1("a color", |c| c.is_ascii_lowercase())?;
+    Ok(Bag::from(adjective, color))
+}
+
+pub type Dependencies = HashMap<Bag, Vec<(usize, Bag)>>;
+
+/// Deserialized from its name, like `"shiny gold"`.
+#[derive(Debug, Eq, PartialEq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Bag {
+    adjective: String,
+    color: String,
+}
+
+impl Bag {
+    fn from(adjective: impl Into<String>, color: impl Into<String>) -> Bag {
+        Bag {
+            adjective: adjective.into(),
+            color: color.into(),
+        }
+    }
+}
+
+impl TryFrom<String> for Bag {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        match name.split(' ').collect::<Vec<_>>()[..] {
+            [adjective, color] => Ok(Bag::from(adjective, color)),
+            _ => Err(format!("`{}` is not an adjective and a color", name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_solution_1() {
+        let demo_input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+        dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+        bright white bags contain 1 shiny gold bag.
+        muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+        shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+        dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+        vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+        faded blue bags contain no other bags.
+        dotted black bags contain no other bags.";
+        let solution = solve_first(
+            &parse_dependencies(demo_input).unwrap(),
+            &Bag::from("shiny", "gold"),
+        );
+        assert_eq!(solution, 4);
+    }
+    #[test]
+    fn seoncd_demo_solution_1() {
+        let demo_input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+        dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+        bright white bags contain 1 shiny gold bag.
+        muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+        shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+        dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+        vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+        faded blue bags contain no other bags.
+        dotted black bags contain no other bags.";
+        let solution = solve_second(
+            &parse_dependencies(demo_input).unwrap(),
+            &Bag::from("shiny", "gold"),
+        );
+        assert_eq!(solution, 32);
+    }
+    #[test]
+    fn second_demo_solution_2() {
+        let demo_input = "shiny gold bags contain 2 dark red bags.
+        dark red bags contain 2 dark orange bags.
+        dark orange bags contain 2 dark yellow bags.
+        dark yellow bags contain 2 dark green bags.
+        dark green bags contain 2 dark blue bags.
+        dark blue bags contain 2 dark violet bags.
+        dark violet bags contain no other bags.";
+        let solution = solve_second(
+            &parse_dependencies(demo_input).unwrap(),
+            &Bag::from("shiny", "gold"),
+        );
+        assert_eq!(solution, 126);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{all_consuming, cut, map_res, recognize},
+    error::{context, VerboseError, VerboseErrorKind},
+    sequence::{preceded, tuple},
+};
+use nom::{combinator::map, IResult};
+
+use crate::{
+    parsing::{self, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Default)]
+pub struct Day08;
+
+impl Day for Day08 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Answer {
+        solve_first(instructions).into()
+    }
+
+    fn part_two(&self, instructions: &Self::Input) -> Option<Answer> {
+        Some(solve_second(instructions).into())
+    }
+}
+
+fn solve_first(instructions: &[Instruction]) -> isize {
+    let mut acc = 0;
+    let mut visited = HashSet::new();
+    let mut next_instruction = 0;
+    while !visited.contains(&next_instruction) {
+        visited.insert(next_instruction);
+        let instruction = &instructions[next_instruction];
+        use Instruction::*;
+        match instruction {
+            Nop(_) => next_instruction += 1,
+            Acc(increment) => {
+                acc += increment;
+                next_instruction += 1;
+            }
+            Jmp(difference) => {
+                next_instruction = ((next_instruction as isize) + difference) as usize
+            }
+        }
+    }
+
+    acc
+}
+
+fn solve_second(instructions: &[Instruction]) -> isize {
+    let uncorrupted = uncorrupt(instructions);
+
+    for ins in uncorrupted {
+        let (is_infinite, acc) = infinite_loop(ins);
+        if !is_infinite {
+            return acc;
+        }
+    }
+
+    unreachable!();
+}
+
+fn infinite_loop(ins: Vec<Instruction>) -> (bool, isize) {
+    let mut acc = 0;
+    let mut visited = HashSet::new();
+    let mut next_instruction = 0;
+    while !visited.contains(&next_instruction) && next_instruction < ins.len() {
+        visited.insert(next_instruction);
+        let instruction = &ins[next_instruction];
+        use Instruction::*;
+        match instruction {
+            Nop(_) => next_instruction += 1,
+            Acc(increment) => {
+                acc += increment;
+                next_instruction += 1;
+            }
+            Jmp(difference) => {
+                next_instruction = ((next_instruction as isize) + difference) as usize
+            }
+        }
+    }
+
+    (visited.contains(&next_instruction), acc)
+}
+
+fn parse_lines(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<_> = parsing::lines(input)
+        .map(Instruction::from)
+        .collect::<Result<_, _>>()?;
+    if instructions.is_empty() {
+        return Err(parsing::end_of_input(input, "`nop`, `acc` or `jmp`"));
+    }
+    Ok(instructions)
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Nop(isize),
+    Acc(isize),
+    Jmp(isize),
+}
+
+impl Instruction {
+    fn from(line: Line) -> Result<Instruction, ParseError> {
+        let mut parser = all_consuming(context(
+            "`nop`, `acc` or `jmp`",
+            alt((nop_parser, acc_parser, jmp_parser)),
+        ));
+        match parser(line.text) {
+            Ok((_, instruction)) => Ok(instruction),
+            Err(nom::Err::Error(error)) | Err(nom::Err::Failure(error)) => {
+                Err(to_parse_error(line, error))
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!("Only complete parsers are used."),
+        }
+    }
+}
+
+/// Reports the innermost context, or the end of the line if nothing but trailing input failed.
+fn to_parse_error(line: Line, error: VerboseError<&str>) -> ParseError {
+    let innermost_context = error.errors.iter().find_map(|(rest, kind)| match kind {
+        VerboseErrorKind::Context(expected) => Some((rest, *expected)),
+        _ => None,
+    });
+    let (rest, expected) = innermost_context.unwrap_or((&error.errors[0].0, "end of line"));
+    line.error_at(line.text.len() - rest.len(), expected)
+}
+
+type ParseResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+fn nop_parser(input: &str) -> ParseResult<'_, Instruction> {
+    map(preceded(tag("nop "), cut(number_parser)), Instruction::Nop)(input)
+}
+fn acc_parser(input: &str) -> ParseResult<'_, Instruction> {
+    map(preceded(tag("acc "), cut(number_parser)), Instruction::Acc)(input)
+}
+fn jmp_parser(input: &str) -> ParseResult<'_, Instruction> {
+    map(preceded(tag("jmp "), cut(number_parser)), Instruction::Jmp)(input)
+}
+
+fn number_parser(input: &str) -> ParseResult<'_, isize> {
+    context(
+        "a smaller number",
+        map_res(
+            recognize(tuple((
+                context("`+` or `-`", alt((tag("+"), tag("-")))),
+                context("a number", digit1),
+            ))),
+            str::parse,
+        ),
+    )(input)
+}
+
+fn uncorrupt(instructions: &[Instruction]) -> Vec<Vec<Instruction>> {
+    instructions
+        .iter()
+        .enumerate()
+        .map(|(index, _)| index)
+        .map(|index| {
+            let mut cloned = instructions.to_vec();
+            use Instruction::*;
+            cloned[index] = match cloned[index] {
+                Nop(n) => Jmp(n),
+                Jmp(n) => Nop(n),
+                Acc(n) => Acc(n),
+            };
+            cloned
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_solution_1() {
+        let demo_input = "nop +0
+        acc +1
+        jmp +4
+        acc +3
+        jmp -3
+        acc -99
+        acc +1
+        jmp -4
+        acc +6";
+        let solution = solve_first(&parse_lines(demo_input).unwrap());
+        assert_eq!(solution, 5);
+    }
+    #[test]
+    fn demo_solution_2() {
+        let demo_input = "nop +0
+        acc +1
+        jmp +4
+        acc +3
+        jmp -3
+        acc -99
+        acc +1
+        jmp -4
+        acc +6";
+        let solution = solve_second(&parse_lines(demo_input).unwrap());
+        assert_eq!(solution, 8);
+    }
+
+    #[test]
+    fn reports_malformed_instruction() {
+        let demo_input = "nop +0
+        acc 1";
+        let error = parse_lines(demo_input).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 13,
+                expected: "`+` or `-`".to_string(),
+                section: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::{
+    parameters::positive,
+    parsing::{self, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day09 {
+    /// How many preceding numbers a number has to be the sum of two of.
+    #[serde(deserialize_with = "positive")]
+    pub preamble: usize,
+}
+
+impl Default for Day09 {
+    fn default() -> Self {
+        Day09 { preamble: 25 }
+    }
+}
+
+impl Day for Day09 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, numbers: &Self::Input) -> Answer {
+        match solve_first(numbers, self.preamble) {
+            Some(number) => number.into(),
+            None => self.all_valid(numbers),
+        }
+    }
+
+    fn part_two(&self, numbers: &Self::Input) -> Option<Answer> {
+        Some(match solve_second(numbers, self.preamble) {
+            Ok(weakness) => weakness.into(),
+            Err(Some(invalid)) => Answer::Missing(format!(
+                "no contiguous numbers sum up to {}",
+                numbers[invalid]
+            )),
+            Err(None) => self.all_valid(numbers),
+        })
+    }
+}
+
+impl Day09 {
+    fn all_valid(&self, numbers: &[usize]) -> Answer {
+        Answer::Missing(if numbers.len() <= self.preamble {
+            format!(
+                "the preamble of {} is not shorter than the {} numbers",
+                self.preamble,
+                numbers.len()
+            )
+        } else {
+            "every number is the sum of two of the preamble".to_string()
+        })
+    }
+}
+
+/// The index of the first number that is not the sum of two of the `preamble_size` numbers
+/// before it.
+fn find_invalid(numbers: &[usize], preamble_size: usize) -> Option<usize> {
+    (preamble_size..numbers.len()).find(|&i| !is_valid(&numbers[i - preamble_size..i], numbers[i]))
+}
+
+fn solve_first(numbers: &[usize], preamble_size: usize) -> Option<usize> {
+    find_invalid(numbers, preamble_size).map(|i| numbers[i])
+}
+
+/// The weakness, or the index of the invalid number if no chain sums up to it.
+fn solve_second(numbers: &[usize], preamble_size: usize) -> Result<usize, Option<usize>> {
+    let i = find_invalid(numbers, preamble_size).ok_or(None)?;
+    find_chain(&numbers[0..i], numbers[i]).ok_or(Some(i))
+}
+
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let numbers: Vec<_> = parsing::lines(input)
+        .map(|line| line.number())
+        .collect::<Result<_, _>>()?;
+    if numbers.is_empty() {
+        return Err(parsing::end_of_input(input, "a number"));
+    }
+    Ok(numbers)
+}
+
+fn is_valid(preamble: &[usize], next: usize) -> bool {
+    preamble
+        .iter()
+        .tuple_combinations()
+        .any(|(a, b)| a + b == next)
+}
+
+fn find_chain(candidates: &[usize], target: usize) -> Option<usize> {
+    for start in 0..candidates.len() {
+        for end in start + 1..candidates.len() {
+            let chain = &candidates[start..end];
+            let sum: usize = chain.iter().sum();
+            if sum == target {
+                return Some(chain.iter().min()? + chain.iter().max()?);
+            } else if sum > target {
+                break;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demo_solution_1() {
+        let demo_input = "
+        35
+        20
+        15
+        25
+        47
+        40
+        62
+        55
+        65
+        95
+        102
+        117
+        150
+        182
+        127
+        219
+        299
+        277
+        309
+        576";
+        let solution = solve_first(&parse(demo_input).unwrap(), 5);
+        assert_eq!(solution, Some(127));
+    }
+    #[test]
+    fn demo_solution_2() {
+        let demo_input = "
+        35
+        20
+        15
+        25
+        47
+        40
+        62
+        55
+        65
+        95
+        102
+        117
+        150
+        182
+        127
+        219
+        299
+        277
+        309
+        576";
+        let solution = solve_second(&parse(demo_input).unwrap(), 5);
+        assert_eq!(solution, Ok(62));
+    }
+
+    #[test]
+    fn explains_missing_answers() {
+        let numbers = parse("1\n2\n3").unwrap();
+        assert_eq!(
+            Day09 { preamble: 3 }.part_one(&numbers),
+            Answer::Missing("the preamble of 3 is not shorter than the 3 numbers".to_string())
+        );
+        assert_eq!(solve_first(&numbers, 1), Some(2));
+        assert_eq!(
+            Day09 { preamble: 1 }.part_two(&numbers),
+            Some(Answer::Missing(
+                "no contiguous numbers sum up to 2".to_string()
+            ))
+        );
+        assert_eq!(solve_second(&numbers, 2), Err(None));
+    }
+}
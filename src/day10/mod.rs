@@ -2,18 +2,32 @@ use std::collections::HashMap;
 
 use petgraph::{algo::toposort, graphmap::GraphMap, Directed};
 
-fn main() {
-    let input = include_str!("./input.txt");
+use crate::{
+    parsing::{self, ParseError},
+    Answer, Day,
+};
 
-    let first_solution = solve_first(input);
-    println!("The first solution is:\n{}", first_solution);
+#[derive(Default)]
+pub struct Day10;
 
-    let second_solution = solve_second(input);
-    println!("The second solution is:\n{}", second_solution);
+impl Day for Day10 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, adapters: &Self::Input) -> Answer {
+        solve_first(adapters).into()
+    }
+
+    fn part_two(&self, adapters: &Self::Input) -> Option<Answer> {
+        Some(solve_second(adapters).into())
+    }
 }
 
-fn solve_first(input: &str) -> usize {
-    let mut numbers = parse(input);
+fn solve_first(adapters: &[usize]) -> usize {
+    let mut numbers = adapters.to_vec();
     numbers.insert(0, 0);
 
     numbers.sort_unstable();
@@ -27,13 +41,13 @@ fn solve_first(input: &str) -> usize {
 
     if !rest.iter().all(|difference| difference <= &3) {
         panic!("Too much difference.");
-    } else {
-        return one.len() * (three.len() + 1); // Built-in is always 3 higher.
     }
+
+    one.len() * (three.len() + 1) // Built-in is always 3 higher.
 }
 
-fn solve_second(input: &str) -> usize {
-    let mut numbers = parse(input);
+fn solve_second(adapters: &[usize]) -> usize {
+    let mut numbers = adapters.to_vec();
     numbers.insert(0, 0);
     let max = numbers.iter().max().unwrap();
     let end = max + 3;
@@ -54,14 +68,8 @@ fn solve_second(input: &str) -> usize {
     *weights.get(&0).unwrap()
 }
 
-fn parse(input: &str) -> Vec<usize> {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| line.parse())
-        .collect::<Result<_, _>>()
-        .unwrap()
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    parsing::lines(input).map(|line| line.number()).collect()
 }
 
 fn make_graph(mut numbers: Vec<usize>) -> GraphMap<usize, usize, Directed> {
@@ -72,7 +80,7 @@ fn make_graph(mut numbers: Vec<usize>) -> GraphMap<usize, usize, Directed> {
     numbers.iter().for_each(|weight| {
         graph.add_node(*weight);
 
-        &[1, 2, 3].iter().for_each(|rating| {
+        [1, 2, 3].iter().for_each(|rating| {
             let maybe_input_weight = weight.checked_sub(*rating);
             if let Some(input_weight) = maybe_input_weight {
                 if graph.contains_node(input_weight) {
@@ -102,7 +110,7 @@ mod tests {
         6
         12
         4";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap());
         assert_eq!(solution, 7 * 5);
     }
     #[test]
@@ -138,7 +146,7 @@ mod tests {
         34
         10
         3";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap());
         assert_eq!(solution, 22 * 10);
     }
 
@@ -155,7 +163,7 @@ mod tests {
         6
         12
         4";
-        let solution = solve_second(demo_input);
+        let solution = solve_second(&parse(demo_input).unwrap());
         assert_eq!(solution, 8);
     }
     #[test]
@@ -191,7 +199,7 @@ mod tests {
         34
         10
         3";
-        let solution = solve_second(demo_input);
+        let solution = solve_second(&parse(demo_input).unwrap());
         assert_eq!(solution, 19208);
     }
 }
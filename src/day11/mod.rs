@@ -0,0 +1,161 @@
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt::{Debug, Display},
+};
+
+use crate::{
+    grid::{Grid, Position, ALL_DIRECTIONS},
+    parsing::ParseError,
+    Answer, Day,
+};
+
+#[derive(Default)]
+pub struct Day11;
+
+impl Day for Day11 {
+    type Input = Board;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, board: &Self::Input) -> Answer {
+        solve_first(board).into()
+    }
+
+    fn part_two(&self, board: &Self::Input) -> Option<Answer> {
+        Some(solve_second(board).into())
+    }
+}
+
+fn solve_first(initial: &Board) -> usize {
+    simulate(initial, 4, |board, position| {
+        board
+            .neighbours8(position)
+            .filter(|&neighbour| board[neighbour] == Cell::Seat(true))
+            .count()
+    })
+}
+
+fn solve_second(initial: &Board) -> usize {
+    simulate(initial, 5, |board, position| {
+        ALL_DIRECTIONS
+            .iter()
+            .filter(|&&direction| {
+                board
+                    .ray(position, direction)
+                    .map(|seen| board[seen])
+                    .find(|cell| *cell != Cell::Floor)
+                    == Some(Cell::Seat(true))
+            })
+            .count()
+    })
+}
+
+/// Lets people take and leave seats until nothing changes, and counts the occupied seats in the
+/// end. A seat is left once `tolerance` of the seats counted by `occupied_neighbors` are taken.
+fn simulate(
+    initial: &Board,
+    tolerance: usize,
+    occupied_neighbors: impl Fn(&Board, Position) -> usize,
+) -> usize {
+    use Cell::*;
+
+    let mut board = initial.clone();
+    loop {
+        let mut next_board = board.clone();
+        let mut changed = false;
+        for position in board.positions() {
+            let next_cell = match board[position] {
+                Seat(false) if occupied_neighbors(&board, position) == 0 => Seat(true),
+                Seat(true) if occupied_neighbors(&board, position) >= tolerance => Seat(false),
+                cell => cell,
+            };
+            if next_cell != board[position] {
+                next_board[position] = next_cell;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+        board = next_board;
+    }
+
+    board.cells().filter(|c| c == &&Seat(true)).count()
+}
+
+fn parse(input: &str) -> Result<Board, ParseError> {
+    Grid::parse(input, "`.`, `L` or `#`", |c| c.try_into().ok())
+}
+
+pub type Board = Grid<Cell>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum Cell {
+    Floor,
+    Seat(bool),
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Cell::*;
+        f.write_str(match self {
+            Floor => ".",
+            Seat(true) => "#",
+            Seat(false) => "L",
+        })
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Cell::*;
+        match value {
+            '.' => Ok(Floor),
+            'L' => Ok(Seat(false)),
+            '#' => Ok(Seat(true)),
+            invalid => Err(invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_demo_solution() {
+        let demo_input = "L.LL.LL.LL
+        LLLLLLL.LL
+        L.L.L..L..
+        LLLL.LL.LL
+        L.LL.LL.LL
+        L.LLLLL.LL
+        ..L.L.....
+        LLLLLLLLLL
+        L.LLLLLL.L
+        L.LLLLL.LL";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 37);
+    }
+
+    #[test]
+    fn second_demo_solution() {
+        let demo_input = "L.LL.LL.LL
+        LLLLLLL.LL
+        L.L.L..L..
+        LLLL.LL.LL
+        L.LL.LL.LL
+        L.LLLLL.LL
+        ..L.L.....
+        LLLLLLLLLL
+        L.LLLLLL.L
+        L.LLLLL.LL";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 26);
+    }
+}
@@ -1,17 +1,30 @@
 use std::convert::{TryFrom, TryInto};
 
-fn main() {
-    let input = include_str!("./input.txt");
+use crate::{
+    parsing::{self, ParseError},
+    Answer, Day,
+};
 
-    let first_solution = solve_first(input);
-    println!("The first solution is:\n{}", first_solution);
+#[derive(Default)]
+pub struct Day12;
 
-    let second_solution = solve_second(input);
-    println!("The second solution is:\n{}", second_solution);
+impl Day for Day12 {
+    type Input = Vec<(Instruction, isize)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Answer {
+        solve_first(instructions).into()
+    }
+
+    fn part_two(&self, instructions: &Self::Input) -> Option<Answer> {
+        Some(solve_second(instructions).into())
+    }
 }
 
-fn solve_first(input: &str) -> isize {
-    let instructions = parse(input);
+fn solve_first(instructions: &[(Instruction, isize)]) -> isize {
     let (pos, _) =
         instructions
             .iter()
@@ -45,8 +58,7 @@ fn solve_first(input: &str) -> isize {
     pos.0.abs() + pos.1.abs()
 }
 
-fn solve_second(input: &str) -> isize {
-    let instructions = parse(input);
+fn solve_second(instructions: &[(Instruction, isize)]) -> isize {
     let (pos, _): ((isize, isize), _) =
         instructions
             .iter()
@@ -77,25 +89,22 @@ fn solve_second(input: &str) -> isize {
                 }
             });
 
-    println!("{:?}", pos);
     pos.0.abs() + pos.1.abs()
 }
 
-fn parse(input: &str) -> Vec<(Instruction, isize)> {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
+fn parse(input: &str) -> Result<Vec<(Instruction, isize)>, ParseError> {
+    parsing::lines(input)
         .map(|line| {
-            (
-                line.chars().nth(0).unwrap().try_into().unwrap(),
-                line.chars().skip(1).collect::<String>().parse().unwrap(),
-            )
+            let mut cursor = line.cursor();
+            let instruction = cursor.char("one of `NESWLRF`", |c| c.try_into().ok())?;
+            let number = cursor.number()?;
+            cursor.end()?;
+            Ok((instruction, number))
         })
         .collect()
 }
 
-enum Instruction {
+pub enum Instruction {
     North,
     East,
     South,
@@ -134,7 +143,7 @@ mod tests {
         F7
         R90
         F11";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap());
         assert_eq!(solution, 25);
     }
 
@@ -143,7 +152,7 @@ mod tests {
         let demo_input = "F10
         R180
         F10";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap());
         assert_eq!(solution, 0);
     }
 
@@ -154,7 +163,14 @@ mod tests {
         F7
         R90
         F11";
-        let solution = solve_second(demo_input);
+        let solution = solve_second(&parse(demo_input).unwrap());
         assert_eq!(solution, 286);
     }
+
+    #[test]
+    fn reports_unknown_action() {
+        let error = parse("F10\nX3").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "one of `NESWLRF`");
+    }
 }
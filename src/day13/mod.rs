@@ -0,0 +1,163 @@
+use modinverse::modinverse;
+
+use crate::{
+    parsing::{self, ParseError},
+    Answer, Day,
+};
+
+#[derive(Default)]
+pub struct Day13;
+
+impl Day for Day13 {
+    type Input = Notes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, notes: &Self::Input) -> Answer {
+        solve_first(notes).into()
+    }
+
+    fn part_two(&self, notes: &Self::Input) -> Option<Answer> {
+        Some(solve_second(notes).into())
+    }
+}
+
+fn solve_first((arrival_time, busses): &Notes) -> usize {
+    let arrival_time = *arrival_time;
+    let mut time = arrival_time;
+
+    loop {
+        let maybe_bus = busses.iter().find(|(_, bus)| time % *bus == 0);
+        if let Some((_, bus)) = maybe_bus {
+            return *bus * (time - arrival_time);
+        }
+
+        time += 1;
+    }
+}
+
+fn solve_second((_, busses): &Notes) -> i128 {
+    let n: i128 = busses.iter().map(|(_, bus)| *bus as i128).product();
+    let result: i128 = busses
+        .iter()
+        .map(|(index_, bus_)| {
+            let index = *index_ as i128;
+            let bus = *bus_ as i128;
+
+            let a = (bus - index) % bus;
+            let y = n / bus;
+            let z = modinverse(y, bus).unwrap();
+
+            a * y * z
+        })
+        .sum();
+    result % n
+}
+
+/// The earliest departure time and the busses with their offsets.
+pub type Notes = (usize, Vec<(usize, usize)>);
+
+fn parse(input: &str) -> Result<Notes, ParseError> {
+    let mut lines = parsing::lines(input);
+
+    let time = lines
+        .next()
+        .ok_or_else(|| parsing::end_of_input(input, "the earliest departure time"))?
+        .number()?;
+
+    let schedule = lines
+        .next()
+        .ok_or_else(|| parsing::end_of_input(input, "the bus schedule"))?;
+    let mut cursor = schedule.cursor();
+    let mut busses = Vec::new();
+    for index in 0.. {
+        match cursor.peek() {
+            Some('x') => cursor.tag("x")?,
+            Some(c) if c.is_ascii_digit() => busses.push((index, cursor.number()?)),
+            _ => return Err(cursor.error("a bus or `x`")),
+        }
+
+        if !cursor.optional_tag(",") {
+            break;
+        }
+    }
+    cursor.end()?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error_at(0, "end of input"));
+    }
+
+    Ok((time, busses))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_demo_solution() {
+        let demo_input = "939
+        7,13,x,x,59,x,31,19";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 59 * 5);
+    }
+
+    #[test]
+    fn second_demo_solution_1() {
+        let demo_input = "939
+        7,13,x,x,59,x,31,19";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 1068781);
+    }
+    #[test]
+    fn second_demo_solution_2() {
+        let demo_input = "939
+        17,x,13,19";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 3417);
+    }
+    #[test]
+    fn second_demo_solution_3() {
+        let demo_input = "939
+        67,7,59,61";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 754018);
+    }
+    #[test]
+    fn second_demo_solution_4() {
+        let demo_input = "939
+        67,x,7,59,61";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 779210);
+    }
+    #[test]
+    fn second_demo_solution_5() {
+        let demo_input = "939
+        67,7,x,59,61";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 1261476);
+    }
+    #[test]
+    fn second_demo_solution_6() {
+        let demo_input = "939
+        1789,37,47,1889";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 1202161486);
+    }
+    #[test]
+    fn second_demo_solution_7() {
+        let demo_input = "939
+        2,3,5";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 8);
+    }
+    #[test]
+    fn second_demo_solution_8() {
+        let demo_input = "939
+        3,2,5";
+        let solution = solve_second(&parse(demo_input).unwrap());
+        assert_eq!(solution, 3);
+    }
+}
@@ -1,26 +1,37 @@
-use lazy_static::lazy_static;
-use regex::Regex;
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
 };
 
-fn main() {
-    let input = include_str!("./input.txt");
+use crate::{
+    parsing::{self, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Default)]
+pub struct Day14;
+
+impl Day for Day14 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
 
-    let first_solution = solve_first(input);
-    println!("The first solution is:\n{}", first_solution);
+    fn part_one(&self, instructions: &Self::Input) -> Answer {
+        solve_first(instructions).into()
+    }
 
-    let second_solution = solve_second(input);
-    println!("The second solution is:\n{}", second_solution);
+    fn part_two(&self, instructions: &Self::Input) -> Option<Answer> {
+        Some(solve_second(instructions).into())
+    }
 }
 
-fn solve_first(input: &str) -> u64 {
-    let instructions = parse(input);
+fn solve_first(instructions: &[Instruction]) -> u64 {
     let mut instructions_iter = instructions.iter();
 
     let mut mask = if let Instruction::Mask(mask) = instructions_iter.next().unwrap() {
-        mask
+        Mask::from_str(mask).unwrap()
     } else {
         unreachable!()
     };
@@ -28,7 +39,7 @@ fn solve_first(input: &str) -> u64 {
 
     for i in instructions_iter {
         match i {
-            Instruction::Mask(new_mask) => mask = new_mask,
+            Instruction::Mask(new_mask) => mask = Mask::from_str(new_mask).unwrap(),
             Instruction::Mem(address, value) => {
                 let masked_value = mask.apply(*value);
                 memory.insert(address, masked_value);
@@ -39,12 +50,11 @@ fn solve_first(input: &str) -> u64 {
     memory.values().sum()
 }
 
-fn solve_second(input: &str) -> u64 {
-    let instructions = parse2(input);
+fn solve_second(instructions: &[Instruction]) -> u64 {
     let mut instructions_iter = instructions.iter();
 
-    let mut mask = if let Instruction2::Mask(mask) = instructions_iter.next().unwrap() {
-        mask
+    let mut mask = if let Instruction::Mask(mask) = instructions_iter.next().unwrap() {
+        Mask2::from_str(mask).unwrap()
     } else {
         unreachable!()
     };
@@ -52,8 +62,8 @@ fn solve_second(input: &str) -> u64 {
 
     for i in instructions_iter {
         match i {
-            Instruction2::Mask(new_mask) => mask = new_mask,
-            Instruction2::Mem(address, value) => {
+            Instruction::Mask(new_mask) => mask = Mask2::from_str(new_mask).unwrap(),
+            Instruction::Mem(address, value) => {
                 let addresses = mask.apply(*address);
                 for a in addresses {
                     memory.insert(a, *value);
@@ -64,63 +74,54 @@ fn solve_second(input: &str) -> u64 {
 
     memory.values().sum()
 }
-lazy_static! {
-    static ref MASK_REGEX: Regex = Regex::new(r"mask = (?P<mask>[X01]{36})").unwrap();
-    static ref MEM_REGEX: Regex = Regex::new(r"mem\[(?P<address>\d+)\] = (?P<value>\d+)").unwrap();
-}
 
-fn parse(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            if let Some(cap) = MASK_REGEX.captures(line) {
-                return Instruction::Mask(Mask::from_str(&cap["mask"]).unwrap());
-            }
+const MASK_LENGTH: usize = 36;
 
-            if let Some(cap) = MEM_REGEX.captures(line) {
-                return Instruction::Mem(
-                    cap["address"].parse().unwrap(),
-                    cap["value"].parse().unwrap(),
-                );
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<_> = parsing::lines(input)
+        .enumerate()
+        .map(|(index, line)| {
+            let instruction = parse_line(line)?;
+            if index == 0 && !matches!(instruction, Instruction::Mask(_)) {
+                return Err(line.error_at(0, "`mask` before the first `mem`"));
             }
-
-            unreachable!();
+            Ok(instruction)
         })
-        .collect()
+        .collect::<Result<_, _>>()?;
+    if instructions.is_empty() {
+        return Err(parsing::end_of_input(input, "`mask = …`"));
+    }
+    Ok(instructions)
 }
 
-fn parse2(input: &str) -> Vec<Instruction2> {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            if let Some(cap) = MASK_REGEX.captures(line) {
-                return Instruction2::Mask(Mask2::from_str(&cap["mask"]).unwrap());
-            }
-
-            if let Some(cap) = MEM_REGEX.captures(line) {
-                return Instruction2::Mem(
-                    cap["address"].parse().unwrap(),
-                    cap["value"].parse().unwrap(),
-                );
-            }
+fn parse_line(line: Line) -> Result<Instruction, ParseError> {
+    let mut cursor = line.cursor();
+    let instruction = if cursor.optional_tag("mask = ") {
+        let mask = (0..MASK_LENGTH)
+            .map(|_| {
+                cursor.char("`X`, `0` or `1`", |c| {
+                    Some(c).filter(|c| "X01".contains(*c))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Instruction::Mask(mask)
+    } else if cursor.optional_tag("mem[") {
+        let address = cursor.number()?;
+        cursor.tag("] = ")?;
+        let value = cursor.number()?;
+        Instruction::Mem(address, value)
+    } else {
+        return Err(cursor.error("`mask` or `mem`"));
+    };
+    cursor.end()?;
 
-            unreachable!();
-        })
-        .collect()
-}
-#[derive(Debug)]
-enum Instruction {
-    Mask(Mask),
-    Mem(u64, u64),
+    Ok(instruction)
 }
 
+/// The mask is kept raw, since both parts interpret it differently.
 #[derive(Debug)]
-enum Instruction2 {
-    Mask(Mask2),
+pub enum Instruction {
+    Mask(String),
     Mem(u64, u64),
 }
 
@@ -165,23 +166,6 @@ impl Mask {
     fn apply(&self, value: u64) -> u64 {
         (value | self.ones) & self.zeroes
     }
-
-    fn new() -> Self {
-        Self {
-            ones: 0,
-            zeroes: u64::MAX,
-        }
-    }
-
-    fn set_one_at(&mut self, pow: u64) {
-        self.ones |= 1 << pow;
-        self.zeroes |= 1 << pow;
-    }
-
-    fn set_zero_at(&mut self, pow: u64) {
-        self.zeroes &= u64::MAX - 1 << pow;
-        self.ones &= u64::MAX - 1 << pow;
-    }
 }
 #[derive(Debug)]
 struct Mask2 {
@@ -249,7 +233,7 @@ mod tests {
         mem[8] = 11
         mem[7] = 101
         mem[8] = 0";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap());
         assert_eq!(solution, 101 + 64);
     }
 
@@ -259,7 +243,7 @@ mod tests {
         mem[8] = 11
         mem[7] = 101
         mem[8] = 2";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap());
         assert_eq!(solution, 101 + 64);
     }
     #[test]
@@ -268,7 +252,29 @@ mod tests {
         mem[42] = 100
         mask = 00000000000000000000000000000000X0XX
         mem[26] = 1";
-        let solution = solve_second(demo_input);
+        let solution = solve_second(&parse(demo_input).unwrap());
         assert_eq!(solution, 208);
     }
+
+    #[test]
+    fn reports_short_mask() {
+        let demo_input = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0
+        mem[8] = 11";
+        let error = parse(demo_input).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 1,
+                column: 43,
+                expected: "`X`, `0` or `1`".to_string(),
+                section: None,
+            }
+        );
+    }
+
+    #[test]
+    fn reports_empty_input() {
+        let error = parse("\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "`mask = …`"));
+    }
 }
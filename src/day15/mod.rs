@@ -0,0 +1,156 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::{
+    parameters::positive,
+    parsing::{self, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day15 {
+    /// The turn whose number is the answer of the first part, counting from 1.
+    #[serde(deserialize_with = "positive")]
+    pub first_turn: usize,
+    /// The turn whose number is the answer of the second part, counting from 1.
+    #[serde(deserialize_with = "positive")]
+    pub second_turn: usize,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            first_turn: 2020,
+            second_turn: 30000000,
+        }
+    }
+}
+
+impl Day for Day15 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, starts: &Self::Input) -> Answer {
+        solve(starts, self.first_turn).into()
+    }
+
+    fn part_two(&self, starts: &Self::Input) -> Option<Answer> {
+        Some(solve(starts, self.second_turn).into())
+    }
+}
+
+fn solve(starts: &[usize], end: usize) -> usize {
+    if end <= starts.len() {
+        return starts[end - 1];
+    }
+
+    let mut last_seen: HashMap<usize, usize> = starts
+        .iter()
+        .enumerate()
+        .map(|(index, number)| (*number, index))
+        .take(starts.len() - 1)
+        .collect();
+
+    //println!("{:?}", last_seen);
+    let mut last: usize = *starts.last().unwrap();
+    for turn in (starts.len() - 1)..(end - 1) {
+        let next = match last_seen.get(&last) {
+            Some(last_seen_turn) => {
+                /*println!(
+                    "{}: Seen {} {} turns ago",
+                    turn,
+                    last,
+                    turn - last_seen_turn
+                );*/
+                turn - last_seen_turn
+            }
+            None => {
+                //println!("{}: Not yet seen {}", turn, last);
+                0
+            }
+        };
+        last_seen.insert(last, turn);
+        last = next;
+    }
+
+    last
+}
+
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut lines = parsing::lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| parsing::end_of_input(input, "the starting numbers"))?;
+
+    let mut cursor = line.cursor();
+    let mut starts = vec![cursor.number()?];
+    while cursor.optional_tag(",") {
+        starts.push(cursor.number()?);
+    }
+    cursor.end()?;
+
+    if let Some(extra) = lines.next() {
+        return Err(extra.error_at(0, "end of input"));
+    }
+
+    Ok(starts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_demo_solution_1() {
+        let demo_input = "0,3,6";
+        let solution = solve(&parse(demo_input).unwrap(), 2020);
+        assert_eq!(solution, 436);
+    }
+    #[test]
+    fn first_demo_solution_2() {
+        let demo_input = "1,3,2";
+        let solution = solve(&parse(demo_input).unwrap(), 2020);
+        assert_eq!(solution, 1);
+    }
+    #[test]
+    fn first_demo_solution_3() {
+        let demo_input = "2,1,3";
+        let solution = solve(&parse(demo_input).unwrap(), 2020);
+        assert_eq!(solution, 10);
+    }
+    #[test]
+    fn first_demo_solution_4() {
+        let demo_input = "1,2,3";
+        let solution = solve(&parse(demo_input).unwrap(), 2020);
+        assert_eq!(solution, 27);
+    }
+    #[test]
+    fn first_demo_solution_5() {
+        let demo_input = "2,3,1";
+        let solution = solve(&parse(demo_input).unwrap(), 2020);
+        assert_eq!(solution, 78);
+    }
+    #[test]
+    fn first_demo_solution_6() {
+        let demo_input = "3,2,1";
+        let solution = solve(&parse(demo_input).unwrap(), 2020);
+        assert_eq!(solution, 438);
+    }
+    #[test]
+    fn first_demo_solution_7() {
+        let demo_input = "3,1,2";
+        let solution = solve(&parse(demo_input).unwrap(), 2020);
+        assert_eq!(solution, 1836);
+    }
+
+    #[test]
+    fn answers_turns_of_the_starting_numbers() {
+        let starts = parse("0,3,6").unwrap();
+        let turns: Vec<_> = (1..=10).map(|turn| solve(&starts, turn)).collect();
+        assert_eq!(turns, vec![0, 3, 6, 0, 3, 3, 1, 0, 4, 0]);
+    }
+}
@@ -0,0 +1,298 @@
+use serde::Deserialize;
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use crate::{
+    parsing::{self, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day16 {
+    /// The fields of your ticket whose values are multiplied in the second part.
+    pub prefix: String,
+}
+
+impl Default for Day16 {
+    fn default() -> Self {
+        Day16 {
+            prefix: "departure".to_string(),
+        }
+    }
+}
+
+impl Day for Day16 {
+    type Input = Notes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, notes: &Self::Input) -> Answer {
+        solve_first(notes).into()
+    }
+
+    fn part_two(&self, notes: &Self::Input) -> Option<Answer> {
+        Some(solve_second(notes, &self.prefix).into())
+    }
+}
+
+fn solve_first((rules, _, other_tickets): &Notes) -> usize {
+    other_tickets
+        .iter()
+        .flatten()
+        .filter(|number| !rules.iter().any(|rule| rule.contains(number)))
+        .sum()
+}
+
+fn solve_second((rules, own_ticket, other_tickets): &Notes, prefix: &str) -> usize {
+    //println!("{:#?}", rules);
+
+    let mut valid_tickets: Vec<Vec<usize>> = other_tickets
+        .iter()
+        .filter(|ticket| {
+            ticket
+                .iter()
+                .all(|number| rules.iter().any(|rule| rule.contains(number)))
+        })
+        .cloned()
+        .collect();
+    valid_tickets.push(own_ticket.clone());
+
+    let transposed: Vec<Vec<usize>> = (0..valid_tickets[0].len())
+        .map(|index| {
+            valid_tickets
+                .iter()
+                .map(|numbers| numbers[index])
+                .collect::<Vec<usize>>()
+        })
+        .collect();
+
+    let mut matching_rules: Vec<HashSet<String>> = transposed
+        .into_iter()
+        .map(|numbers| {
+            //numbers.sort_unstable();
+            //println!("{:?}", numbers);
+            let matching: HashSet<String> = rules
+                .iter()
+                .filter(|rule| numbers.iter().all(|number| rule.contains(number)))
+                .map(|rule| rule.name.to_string())
+                //.inspect(|rule| println!("{}", rule.name))
+                .collect();
+            //println!("{:?}", matching.iter().map(|r| r).collect::<Vec<_>>());
+            matching
+        })
+        .collect();
+
+    while !matching_rules.iter().all(|r| r.len() == 1) {
+        let solved: HashSet<String> = matching_rules
+            .iter()
+            .filter(|r| r.len() == 1)
+            .map(|r| r.iter().next().unwrap())
+            .cloned()
+            .collect();
+        //println!("{:#?}", solved);
+
+        matching_rules = matching_rules
+            .into_iter()
+            .map(|r| {
+                if r.len() > 1 {
+                    r.difference(&solved).cloned().collect()
+                } else {
+                    r
+                }
+            })
+            .collect();
+
+        //println!("{:#?}", matching_rules);
+    }
+    let rule_map = matching_rules
+        .into_iter()
+        .map(|r| r.iter().next().unwrap().clone());
+
+    //println!("{:#?}", matching_rules);
+
+    let departure_values: Vec<usize> = rule_map
+        .enumerate()
+        .filter(|(_, rule)| rule.starts_with(prefix))
+        //.inspect(|(_, rule)| println!("{}", rule.name))
+        .map(|(index, _)| own_ticket[index])
+        .collect();
+
+    //println!("{:#?}", departure_values);
+    departure_values.iter().product()
+}
+
+/// The rules, your own ticket and the nearby tickets.
+pub type Notes = (Vec<Rule<usize>>, Ticket, Vec<Ticket>);
+
+fn parse(input: &str) -> Result<Notes, ParseError> {
+    let mut sections = parsing::sections(input).into_iter();
+
+    let rules: Vec<_> = sections
+        .next()
+        .ok_or_else(|| parsing::end_of_input(input, "the rules"))?
+        .parse(|section| section.lines.iter().copied().map(parse_rule).collect())?;
+
+    let own_section = sections
+        .next()
+        .ok_or_else(|| parsing::end_of_input(input, "`your ticket:`"))?;
+    own_section.expect_header("your ticket")?;
+    let own_ticket = own_section.parse(|section| match section.lines[..] {
+        [line] => parse_ticket(line, rules.len()),
+        [] => {
+            let header = section.first_line();
+            Err(header.error_at(header.text.len(), "your ticket below"))
+        }
+        [_, extra, ..] => Err(extra.error_at(0, "a blank line")),
+    })?;
+
+    let other_section = sections
+        .next()
+        .ok_or_else(|| parsing::end_of_input(input, "`nearby tickets:`"))?;
+    other_section.expect_header("nearby tickets")?;
+    let other_tickets = other_section.parse(|section| {
+        section
+            .lines
+            .iter()
+            .map(|&line| parse_ticket(line, rules.len()))
+            .collect()
+    })?;
+
+    if let Some(extra) = sections.next() {
+        return Err(extra.first_line().error_at(0, "end of input"));
+    }
+
+    Ok((rules, own_ticket, other_tickets))
+}
+
+fn parse_rule(line: Line) -> Result<Rule<usize>, ParseError> {
+    let mut cursor = line.cursor();
+    let name = cursor
+        .take_while1("a field name", |c| c != ':')?
+        .to_string();
+    cursor.tag(": ")?;
+    let r1start = cursor.number()?;
+    cursor.tag("-")?;
+    let r1end = cursor.number()?;
+    cursor.tag(" or ")?;
+    let r2start = cursor.number()?;
+    cursor.tag("-")?;
+    let r2end = cursor.number()?;
+    cursor.end()?;
+
+    Ok(Rule {
+        name,
+        first: r1start..=r1end,
+        second: r2start..=r2end,
+    })
+}
+
+/// Parses a ticket with a value for each of the `fields` rules.
+fn parse_ticket(line: Line, fields: usize) -> Result<Ticket, ParseError> {
+    let mut cursor = line.cursor();
+    let mut ticket = vec![cursor.number()?];
+    while ticket.len() < fields {
+        if !cursor.optional_tag(",") {
+            return Err(cursor.error(format!(
+                "`,` followed by value {} of {}",
+                ticket.len() + 1,
+                fields
+            )));
+        }
+        ticket.push(cursor.number()?);
+    }
+    if cursor.peek() == Some(',') {
+        return Err(cursor.error(format!(
+            "end of line after {} values, one for each rule",
+            fields
+        )));
+    }
+    cursor.end()?;
+    Ok(ticket)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rule<T: PartialOrd> {
+    name: String,
+    first: RangeInclusive<T>,
+    second: RangeInclusive<T>,
+}
+
+impl<T: PartialOrd> Rule<T> {
+    fn contains(&self, candidate: &T) -> bool {
+        self.first.contains(candidate) || self.second.contains(candidate)
+    }
+}
+
+pub type Ticket = Vec<usize>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_demo_solution_1() {
+        let demo_input = "class: 1-3 or 5-7
+        row: 6-11 or 33-44
+        seat: 13-40 or 45-50
+        
+        your ticket:
+        7,1,14
+        
+        nearby tickets:
+        7,3,47
+        40,4,50
+        55,2,20
+        38,6,12";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 71);
+    }
+
+    #[test]
+    fn reports_malformed_rule() {
+        let demo_input = "class: 1-3 or 5-7
+        row: 6-11 and 33-44
+
+        your ticket:
+        7,1,14
+
+        nearby tickets:
+        7,3,47";
+        let error = parse(demo_input).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 18,
+                expected: "` or `".to_string(),
+                section: Some("section 1".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn reports_tickets_of_other_lengths() {
+        let notes = |own, nearby| {
+            let input = format!(
+                "class: 1-3 or 5-7\nrow: 6-11 or 33-44\nseat: 13-40 or 45-50\n\n\
+                 your ticket:\n{}\n\nnearby tickets:\n{}",
+                own, nearby
+            );
+            parse(&input).map_err(|error| (error.line, error.column, error.expected))
+        };
+        assert!(notes("7,1,14", "7,3,47").is_ok());
+        assert_eq!(
+            notes("7,1", "7,3,47").unwrap_err(),
+            (6, 4, "`,` followed by value 3 of 3".to_string())
+        );
+        assert_eq!(
+            notes("7,1,14", "7,3,47,1").unwrap_err(),
+            (
+                9,
+                7,
+                "end of line after 3 values, one for each rule".to_string()
+            )
+        );
+    }
+}
@@ -1,43 +1,61 @@
 use itertools::Itertools;
+use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 
-fn main() {
-    let input = include_str!("./input.txt");
+use crate::{grid::Grid, parsing::ParseError, Answer, Day};
 
-    let first_solution = solve_first(input);
-    println!("The first solution is:\n{}", first_solution);
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day17 {
+    /// How many cycles the cubes are simulated for.
+    pub cycles: usize,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Day17 { cycles: 6 }
+    }
+}
+
+impl Day for Day17 {
+    type Input = Vec<Slice>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, active: &Self::Input) -> Answer {
+        solve_first(active, self.cycles).into()
+    }
 
-    let second_solution = second::solve(input);
-    println!("The second solution is:\n{}", second_solution);
+    fn part_two(&self, active: &Self::Input) -> Option<Answer> {
+        Some(second::solve(active, self.cycles).into())
+    }
 }
 
-fn solve_first(input: &str) -> usize {
-    let mut board = parse(input);
+fn solve_first(active: &[Slice], cycles: usize) -> usize {
+    let mut board = active.iter().map(|&(x, y)| (x, y, 0)).collect();
 
-    for _ in 0..6 {
+    for _ in 0..cycles {
         board = step(board);
     }
 
     board.len()
 }
 
-fn parse(input: &str) -> Board {
-    input
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .enumerate()
-        .flat_map(|(y, cols)| {
-            cols.chars().enumerate().filter_map(move |(x, c)| {
-                if c == '#' {
-                    Some((x as isize, y as isize, 0))
-                } else {
-                    None
-                }
-            })
-        })
-        .collect()
+/// Parses the active cubes of the initial two-dimensional slice.
+fn parse(input: &str) -> Result<Vec<Slice>, ParseError> {
+    let slice = Grid::parse(input, "`.` or `#`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(slice
+        .positions()
+        .filter(|&position| slice[position])
+        .map(|(y, x)| (x as isize, y as isize))
+        .collect())
 }
 
 fn step(board: Board) -> Board {
@@ -86,6 +104,8 @@ type Board = HashSet<Coords>;
 
 type Coords = (isize, isize, isize);
 
+pub type Slice = (isize, isize);
+
 mod second {
     use itertools::Itertools;
     use lazy_static::lazy_static;
@@ -94,34 +114,18 @@ mod second {
         convert::TryInto,
     };
 
-    pub fn solve(input: &str) -> usize {
-        let mut board = parse(input);
+    use super::Slice;
 
-        for _ in 0..6 {
+    pub fn solve(active: &[Slice], cycles: usize) -> usize {
+        let mut board = active.iter().map(|&(x, y)| (x, y, 0, 0)).collect();
+
+        for _ in 0..cycles {
             board = step(board);
         }
 
         board.len()
     }
 
-    fn parse(input: &str) -> Board {
-        input
-            .lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .enumerate()
-            .flat_map(|(y, cols)| {
-                cols.chars().enumerate().filter_map(move |(x, c)| {
-                    if c == '#' {
-                        Some((x as isize, y as isize, 0, 0))
-                    } else {
-                        None
-                    }
-                })
-            })
-            .collect()
-    }
-
     fn step(board: Board) -> Board {
         let mut active_neighbor_counts: HashMap<Coords, usize> = HashMap::new();
         let mut new_board: HashSet<Coords> = HashSet::new();
@@ -191,7 +195,7 @@ mod tests {
         let demo_input = ".#.
         ..#
         ###";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap(), 6);
         assert_eq!(solution, 112);
     }
 
@@ -200,7 +204,7 @@ mod tests {
         let demo_input = ".#.
         ..#
         ###";
-        let solution = second::solve(demo_input);
+        let solution = second::solve(&parse(demo_input).unwrap(), 6);
         assert_eq!(solution, 848);
     }
 }
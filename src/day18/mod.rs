@@ -0,0 +1,158 @@
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use pest::error::{Error, ErrorVariant, InputLocation};
+use pest::iterators::{Pair, Pairs};
+use pest::prec_climber::{Assoc, Operator, PrecClimber};
+use pest::Parser;
+use pest_derive::Parser;
+
+use crate::{
+    parsing::{self, Line, ParseError},
+    Answer, Day,
+};
+
+#[derive(Default)]
+pub struct Day18;
+
+impl Day for Day18 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, lines: &Self::Input) -> Answer {
+        solve_first(lines).into()
+    }
+
+    fn part_two(&self, lines: &Self::Input) -> Option<Answer> {
+        Some(solve_second(lines).into())
+    }
+}
+
+#[derive(Parser)]
+#[grammar = "day18/grammar.pest"]
+struct MyParser;
+
+lazy_static! {
+    static ref PREC_CLIMBER_SAME: PrecClimber<Rule> = {
+        use Assoc::*;
+        use Rule::*;
+
+        PrecClimber::new(vec![
+            Operator::new(add, Left) | Operator::new(multiply, Left),
+        ])
+    };
+    static ref PREC_CLIMBER_ORDERED: PrecClimber<Rule> = {
+        use Assoc::*;
+        use Rule::*;
+
+        PrecClimber::new(vec![
+            Operator::new(multiply, Left),
+            Operator::new(add, Left),
+        ])
+    };
+}
+
+/// The lines are only checked and kept raw, since both parts evaluate them with different
+/// precedences.
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    parsing::lines(input)
+        .map(|line| {
+            MyParser::parse(Rule::calculation, line.text)
+                .map_err(|error| to_parse_error(line, error))?;
+            Ok(line.text.to_string())
+        })
+        .collect()
+}
+
+fn to_parse_error(line: Line, error: Error<Rule>) -> ParseError {
+    let offset = match error.location {
+        InputLocation::Pos(offset) => offset,
+        InputLocation::Span((start, _)) => start,
+    };
+    let expected = match error.variant {
+        ErrorVariant::ParsingError { positives, .. } => positives
+            .iter()
+            .map(|rule| match rule {
+                Rule::num | Rule::int => "a number",
+                Rule::add => "`+`",
+                Rule::multiply => "`*`",
+                Rule::EOI => "end of line",
+                _ => "an expression",
+            })
+            .unique()
+            .join(" or "),
+        ErrorVariant::CustomError { message } => message,
+    };
+    line.error_at(offset, expected)
+}
+
+fn solve_first(lines: &[String]) -> isize {
+    lines
+        .iter()
+        .map(|line| {
+            let pairs: Pairs<Rule> = MyParser::parse(Rule::calculation, line).unwrap();
+            eval(pairs, &PREC_CLIMBER_SAME)
+        })
+        .sum()
+}
+
+fn eval(expression: Pairs<Rule>, climber: &PrecClimber<Rule>) -> isize {
+    climber.climb(
+        expression,
+        |pair: Pair<Rule>| match pair.as_rule() {
+            Rule::num => pair.as_str().parse::<isize>().unwrap(),
+            Rule::expr => eval(pair.into_inner(), climber),
+            _ => unreachable!(),
+        },
+        |lhs: isize, op: Pair<Rule>, rhs: isize| match op.as_rule() {
+            Rule::add => lhs + rhs,
+            Rule::multiply => lhs * rhs,
+            _ => unreachable!(),
+        },
+    )
+}
+
+fn solve_second(lines: &[String]) -> isize {
+    lines
+        .iter()
+        .map(|line| {
+            let pairs: Pairs<Rule> = MyParser::parse(Rule::calculation, line).unwrap();
+            eval(pairs, &PREC_CLIMBER_ORDERED)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_demo_solution_1() {
+        let demo_input = "2 * 3 + (4 * 5)";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 26);
+    }
+
+    #[test]
+    fn first_demo_solution_2() {
+        let demo_input = "5 + (8 * 3 + 9 + 3 * 4 * 3)";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 437);
+    }
+
+    #[test]
+    fn first_demo_solution_3() {
+        let demo_input = "5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 12240);
+    }
+
+    #[test]
+    fn first_demo_solution_4() {
+        let demo_input = "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 13632);
+    }
+}
@@ -1,21 +1,33 @@
 use itertools::Itertools;
-use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::{HashMap, VecDeque};
 
-fn main() {
-    let input = include_str!("./input.txt");
+use crate::{
+    parsing::{self, Cursor, Line, ParseError},
+    Answer, Day,
+};
 
-    let first_solution = solve_first(input);
-    println!("The first solution is:\n{}", first_solution);
+#[derive(Default)]
+pub struct Day19;
 
-    let second_solution = solve_second(input);
-    println!("The second solution is:\n{}", second_solution);
+impl Day for Day19 {
+    type Input = (Rules, Vec<String>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, (rules, messages): &Self::Input) -> Answer {
+        solve_first(rules, messages).into()
+    }
+
+    fn part_two(&self, (rules, messages): &Self::Input) -> Option<Answer> {
+        Some(solve_second(rules, messages).into())
+    }
 }
 
-fn solve_first(input: &str) -> usize {
-    let (rules, messages) = parse(input);
-    let regex = rules_to_regex(&rules);
+fn solve_first(rules: &Rules, messages: &[String]) -> usize {
+    let regex = rules_to_regex(rules);
 
     messages
         .iter()
@@ -23,8 +35,8 @@ fn solve_first(input: &str) -> usize {
         .count()
 }
 
-fn solve_second(input: &str) -> usize {
-    let (mut rules, messages) = parse(input);
+fn solve_second(rules: &Rules, messages: &[String]) -> usize {
+    let mut rules = rules.clone();
 
     rules.insert(8, Rule::Pattern(vec![vec![42], vec![42, 8]]));
     rules.insert(11, Rule::Pattern(vec![vec![42, 31], vec![42, 11, 31]]));
@@ -38,65 +50,98 @@ fn solve_second(input: &str) -> usize {
         .count()
 }
 
-fn parse(input: &str) -> (Rules, Vec<String>) {
-    let mut parts = BLANK_LINE_REGEX.split(input);
+fn parse(input: &str) -> Result<(Rules, Vec<String>), ParseError> {
+    let mut sections = parsing::sections(input).into_iter();
 
-    let rules = parts
+    let rules = sections
         .next()
-        .unwrap()
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| parse_rule(line))
-        .collect();
+        .ok_or_else(|| parsing::end_of_input(input, "the rules"))?
+        .parse(|section| {
+            let mut rules = Rules::new();
+            let mut references = Vec::new();
+            for &line in &section.lines {
+                let (id, rule) = parse_rule(line, &mut references)?;
+                rules.insert(id, rule);
+            }
+            if let Some((_, error)) = references
+                .into_iter()
+                .find(|(id, _)| !rules.contains_key(id))
+            {
+                return Err(error);
+            }
+            if !rules.contains_key(&0) {
+                let last = section.lines.last().copied();
+                let last = last.unwrap_or_else(|| section.first_line());
+                return Err(last.error_at(last.text.len(), "a rule `0: …`"));
+            }
+            Ok(rules)
+        })?;
 
-    let messages = parts
+    let messages = sections
         .next()
-        .unwrap()
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| line.to_string())
+        .ok_or_else(|| parsing::end_of_input(input, "the messages"))?
+        .lines
+        .iter()
+        .map(|line| line.text.to_string())
         .collect();
 
-    (rules, messages)
-}
+    if let Some(extra) = sections.next() {
+        return Err(extra.first_line().error_at(0, "end of input"));
+    }
 
-lazy_static! {
-    static ref BLANK_LINE_REGEX: Regex = Regex::new(r"\n\s*\n").unwrap();
-    static ref RULE_ID_REGEX: Regex = Regex::new(r"(?P<id>\d+): (?P<rule>.+)$").unwrap();
-    static ref LITERAL_RULE_REGEX: Regex = Regex::new(r#""(?P<literal>.)""#).unwrap();
+    Ok((rules, messages))
 }
 
-fn parse_rule(input: &str) -> (RuleId, Rule) {
-    let cap = RULE_ID_REGEX.captures(input).unwrap();
-    let id = cap["id"].parse().unwrap();
-    let raw_rule = &cap["rule"];
-    let rule = if let Some(literal_cap) = LITERAL_RULE_REGEX.captures(raw_rule) {
-        Rule::Literal(literal_cap["literal"].chars().next().unwrap())
+/// Parses a rule, adding the rules it refers to to `references`, each with the error to report if
+/// it is not defined.
+fn parse_rule(
+    line: Line,
+    references: &mut Vec<(RuleId, ParseError)>,
+) -> Result<(RuleId, Rule), ParseError> {
+    let mut cursor = line.cursor();
+    let id = cursor.number()?;
+    cursor.tag(": ")?;
+
+    let rule = if cursor.optional_tag("\"") {
+        let literal = cursor.char("a character", Some)?;
+        cursor.tag("\"")?;
+        Rule::Literal(literal)
     } else {
-        Rule::Pattern(
-            raw_rule
-                .split(" | ")
-                .map(|sequence| {
-                    sequence
-                        .split(" ")
-                        .map(|raw_id| raw_id.parse().unwrap())
-                        .collect()
-                })
-                .collect(),
-        )
+        if !cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+            return Err(cursor.error("a rule number or `\"`"));
+        }
+
+        let mut reference = |cursor: &mut Cursor| {
+            let start = cursor.clone();
+            let id = cursor.number()?;
+            references.push((id, start.error(format!("a defined rule instead of {}", id))));
+            Ok(id)
+        };
+        let mut alternatives = Vec::new();
+        let mut sequence = vec![reference(&mut cursor)?];
+        loop {
+            if cursor.optional_tag(" | ") {
+                alternatives.push(std::mem::take(&mut sequence));
+            } else if !cursor.optional_tag(" ") {
+                break;
+            }
+            sequence.push(reference(&mut cursor)?);
+        }
+        alternatives.push(sequence);
+        Rule::Pattern(alternatives)
     };
-    (id, rule)
+    cursor.end()?;
+
+    Ok((id, rule))
 }
 
-#[derive(Debug)]
-enum Rule {
+#[derive(Debug, Clone)]
+pub enum Rule {
     Pattern(Alternatives),
     Literal(char),
 }
 
-type Rules = HashMap<RuleId, Rule>;
+pub type Rules = HashMap<RuleId, Rule>;
 type Alternatives = Vec<Sequence>;
 type Sequence = Vec<RuleId>;
 type RuleId = usize;
@@ -108,7 +153,7 @@ fn rules_to_regex(rules: &Rules) -> Regex {
 
 fn rules_to_regex_string(rule_id: &RuleId, rules: &Rules) -> String {
     match &rules[rule_id] {
-        Rule::Literal(l) => l.to_string(),
+        Rule::Literal(l) => regex::escape(&l.to_string()),
         Rule::Pattern(alternatives) => {
             format!(
                 "({})",
@@ -154,7 +199,7 @@ fn next(mut possibilities: Possibilities, rules: &Rules) -> Possibilities {
         let mut new_fronts = if let Some(next) = current.remaining.pop_front() {
             match &rules[&next] {
                 Rule::Literal(l) => {
-                    current.candidate.push(l.clone());
+                    current.candidate.push(*l);
                     VecDeque::from(vec![current])
                 }
                 Rule::Pattern(alternatives) => alternatives
@@ -207,7 +252,8 @@ mod tests {
         abbbab
         aaabbb
         aaaabbb"#;
-        let solution = solve_first(demo_input);
+        let (rules, messages) = parse(demo_input).unwrap();
+        let solution = solve_first(&rules, &messages);
         assert_eq!(solution, 2);
     }
 
@@ -261,7 +307,8 @@ mod tests {
         babaaabbbaaabaababbaabababaaab
         aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
         "#;
-        let solution = solve_second(demo_input);
+        let (rules, messages) = parse(demo_input).unwrap();
+        let solution = solve_second(&rules, &messages);
         assert_eq!(solution, 12);
     }
 
@@ -274,7 +321,8 @@ mod tests {
         aa
         b
         "#;
-        let solution = solve_second(demo_input);
+        let (rules, messages) = parse(demo_input).unwrap();
+        let solution = solve_second(&rules, &messages);
         assert_eq!(solution, 1);
     }
 
@@ -288,7 +336,8 @@ mod tests {
         aa
         ab
         "#;
-        let solution = solve_second(demo_input);
+        let (rules, messages) = parse(demo_input).unwrap();
+        let solution = solve_second(&rules, &messages);
         assert_eq!(solution, 2);
     }
     #[test]
@@ -302,7 +351,26 @@ mod tests {
         
         baa
         "#;
-        let solution = solve_second(demo_input);
+        let (rules, messages) = parse(demo_input).unwrap();
+        let solution = solve_second(&rules, &messages);
         assert_eq!(solution, 1);
     }
+
+    #[test]
+    fn reports_undefined_rules() {
+        let error = parse("0: 1 2\n1: \"a\"\n\na").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (1, 6, "a defined rule instead of 2")
+        );
+        let error = parse("1: \"a\"\n\na").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (1, "a rule `0: …`"));
+    }
+
+    #[test]
+    fn matches_literals_with_special_meaning() {
+        let (rules, messages) = parse("0: 1 2\n1: \"(\"\n2: \".\"\n\n(.\n(a").unwrap();
+        assert_eq!(solve_first(&rules, &messages), 1);
+        assert_eq!(solve_second(&rules, &messages), 1);
+    }
 }
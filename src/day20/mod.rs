@@ -1,30 +1,40 @@
-use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
 use std::collections::HashMap;
 use std::convert::TryInto;
 
-fn main() {
-    let input = include_str!("./input.txt");
+use crate::{
+    grid::Grid,
+    parsing::{self, ParseError, Section},
+    Answer, Day,
+};
 
-    let first_solution = solve_first(input);
-    println!("The first solution is:\n{}", first_solution);
+#[derive(Default)]
+pub struct Day20;
 
-    /*let second_solution = solve_second(input);
-    println!("The second solution is:\n{}", second_solution);
-    */
+impl Day for Day20 {
+    type Input = Vec<(Id, [usize; 4])>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, images: &Self::Input) -> Answer {
+        solve_first(images).into()
+    }
+
+    fn part_two(&self, _: &Self::Input) -> Option<Answer> {
+        None
+    }
 }
 
-fn solve_first(input: &str) -> usize {
-    let images = parse(input);
+fn solve_first(images: &[(Id, [usize; 4])]) -> usize {
     //println!("{:#?}", images);
 
     let mut map: HashMap<usize, Vec<Id>> = HashMap::new();
     for (id, borders) in images {
         for border in borders.iter() {
             map.entry(*border)
-                .and_modify(|neighbors| neighbors.push(id))
-                .or_insert_with(|| vec![id]);
+                .and_modify(|neighbors| neighbors.push(*id))
+                .or_insert_with(|| vec![*id]);
         }
     }
     //println!("{:#?}", map);
@@ -56,77 +66,60 @@ fn solve_first(input: &str) -> usize {
     corners.iter().product()
 }
 
-fn parse(input: &str) -> Vec<(Id, [usize; 4])> {
-    let parts = BLANK_LINE_REGEX.split(input);
-
-    parts
-        .map(|image| {
-            let mut lines = image.lines();
-            //println!("{}", image);
-            let id_line = lines.next().unwrap().trim();
-            let id = id_line[5..(id_line.len() - 1)].parse().unwrap();
-            let pixels: Vec<Vec<char>> = lines
-                .map(|line| line.trim())
-                .filter(|line| !line.is_empty())
-                .map(|line| line.chars().collect())
-                .collect();
-            let transposed: Vec<Vec<char>> = (0..pixels[0].len())
-                .map(|index| {
-                    pixels
-                        .iter()
-                        .map(|numbers| numbers[index].clone())
-                        .collect::<Vec<_>>()
-                })
-                .collect();
-            let borders = [
-                pixels[0].clone(),
-                transposed[0].clone(),
-                pixels[pixels.len() - 1].clone(),
-                transposed[transposed.len() - 1].clone(),
-            ]
-            .iter()
-            .map(|border| {
-                let border_number = border
-                    .iter()
-                    .enumerate()
-                    .fold(0usize, |acc, (index, next)| {
-                        let digit = match next {
-                            '#' => 1,
-                            '.' => 0,
-                            _ => unreachable!(),
-                        };
-                        acc + (2usize.pow(index.try_into().unwrap()) * digit)
-                    });
-                let negated = border
-                    .iter()
-                    .rev()
-                    .enumerate()
-                    .fold(0usize, |acc, (index, next)| {
-                        let digit = match next {
-                            '#' => 1,
-                            '.' => 0,
-                            _ => unreachable!(),
-                        };
-                        acc + (2usize.pow(index.try_into().unwrap()) * digit)
-                    });
-                //println!("{}: {} vs {}", id, border_number, negated);
-                std::cmp::min(border_number, negated)
-            })
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-            (id, borders)
+fn parse(input: &str) -> Result<Vec<(Id, [usize; 4])>, ParseError> {
+    let sections = parsing::sections(input);
+    if sections.is_empty() {
+        return Err(parsing::end_of_input(input, "`Tile …:`"));
+    }
+    sections
+        .iter()
+        .map(|section| {
+            let (id, pixels) = parse_tile(section)?;
+            Ok((id, borders(&pixels)))
         })
         .collect()
 }
 
-lazy_static! {
-    static ref BLANK_LINE_REGEX: Regex = Regex::new(r"\n\s*\n").unwrap();
-    static ref RULE_ID_REGEX: Regex = Regex::new(r"(?P<id>\d+): (?P<rule>.+)$").unwrap();
-    static ref LITERAL_RULE_REGEX: Regex = Regex::new(r#""(?P<literal>.)""#).unwrap();
+/// Parses a tile, where `true` marks a `#` pixel.
+fn parse_tile(section: &Section) -> Result<(Id, Grid<bool>), ParseError> {
+    let id = section.expect_id("Tile")?;
+    section.parse(|section| {
+        if section.lines.is_empty() {
+            let header = section.first_line();
+            return Err(header.error_at(header.text.len(), "pixels below"));
+        }
+        let pixels = Grid::parse_lines(&section.lines, "`.` or `#`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok((id, pixels))
+    })
+}
+
+/// The borders of a tile as numbers, choosing the smaller reading direction so that flipped
+/// borders match.
+fn borders(pixels: &Grid<bool>) -> [usize; 4] {
+    let transposed = pixels.transpose();
+    [
+        pixels.row(0),
+        transposed.row(0),
+        pixels.row(pixels.height() - 1),
+        transposed.row(transposed.height() - 1),
+    ]
+    .iter()
+    .map(|border| {
+        let to_number = |number, &pixel| 2 * number + pixel as usize;
+        let border_number = border.iter().rev().fold(0, to_number);
+        let negated = border.iter().fold(0, to_number);
+        std::cmp::min(border_number, negated)
+    })
+    .collect::<Vec<_>>()
+    .try_into()
+    .unwrap()
 }
 
-type Id = usize;
+pub type Id = usize;
 
 #[cfg(test)]
 mod tests {
@@ -241,7 +234,7 @@ mod tests {
         ..#.###...
         ..#.......
         ..#.###...";
-        let solution = solve_first(demo_input);
+        let solution = solve_first(&parse(demo_input).unwrap());
         assert_eq!(solution, 20899048083289);
     }
 }
@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+use crate::{
+    parsing::{self, ParseError, Section},
+    Answer, Day,
+};
+
+#[derive(Default)]
+pub struct Day22;
+
+impl Day for Day22 {
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(&self, decks: &Self::Input) -> Answer {
+        solve_first(decks).into()
+    }
+
+    fn part_two(&self, _: &Self::Input) -> Option<Answer> {
+        None
+    }
+}
+
+fn solve_first((first_deck, second_deck): &(Vec<usize>, Vec<usize>)) -> usize {
+    let mut first = first_deck.iter().copied().collect::<VecDeque<_>>();
+    let mut second = second_deck.iter().copied().collect::<VecDeque<_>>();
+
+    while !first.is_empty() && !second.is_empty() {
+        let one = first.pop_front().unwrap();
+        let two = second.pop_front().unwrap();
+
+        if one > two {
+            first.push_back(one);
+            first.push_back(two);
+        } else {
+            second.push_back(two);
+            second.push_back(one);
+        }
+    }
+
+    let winner = if first.is_empty() { second } else { first };
+
+    winner
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, number)| (index + 1) * number)
+        .sum()
+}
+
+fn parse(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let sections = parsing::sections(input);
+    if let Some(extra) = sections.get(2) {
+        return Err(extra.first_line().error_at(0, "end of input"));
+    }
+    let mut decks = sections
+        .iter()
+        .enumerate()
+        .map(|(index, section)| parse_cards(section, index + 1));
+
+    let first = decks
+        .next()
+        .unwrap_or_else(|| Err(parsing::end_of_input(input, "`Player 1:`")))?;
+    let second = decks
+        .next()
+        .unwrap_or_else(|| Err(parsing::end_of_input(input, "`Player 2:`")))?;
+    Ok((first, second))
+}
+
+fn parse_cards(section: &Section, player: usize) -> Result<Vec<usize>, ParseError> {
+    section.expect_header(&format!("Player {}", player))?;
+    section.parse(|section| section.lines.iter().map(|line| line.number()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_demo_solution_1() {
+        let demo_input = "Player 1:
+        9
+        2
+        6
+        3
+        1
+        
+        Player 2:
+        5
+        8
+        4
+        7
+        10";
+        let solution = solve_first(&parse(demo_input).unwrap());
+        assert_eq!(solution, 306);
+    }
+}
@@ -0,0 +1,347 @@
+//! A rectangular grid of cells, stored row by row in a single vector.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::parsing::{self, Line, ParseError};
+
+/// A `(row, column)` pair, counted from the top left corner.
+pub type Position = (usize, usize);
+
+/// A `(row, column)` step, where negative values move up or left.
+pub type Direction = (isize, isize);
+
+/// The four directions sharing an edge with a cell.
+pub const ORTHOGONAL: [Direction; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The eight directions sharing an edge or a corner with a cell.
+pub const ALL_DIRECTIONS: [Direction; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    ///
+    /// Panics if the cells can't be split into rows of `width`.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells don't fill whole rows");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character, requiring all non-empty lines to have the same length.
+    /// `expected` describes the characters `convert` accepts.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        convert: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<_> = parsing::lines(input).collect();
+        if lines.is_empty() {
+            return Err(parsing::end_of_input(input, expected));
+        }
+        Grid::parse_lines(&lines, expected, convert)
+    }
+
+    /// Like [`Grid::parse`], for lines that have already been split, e.g. by [`parsing::blocks`].
+    pub fn parse_lines(
+        lines: &[Line],
+        expected: &str,
+        convert: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        for line in lines {
+            let mut cursor = line.cursor();
+            match width {
+                None => {
+                    while !cursor.is_at_end() {
+                        cells.push(cursor.char(expected, &convert)?);
+                    }
+                    width = Some(cells.len());
+                }
+                Some(width) => {
+                    for _ in 0..width {
+                        cells.push(cursor.char(expected, &convert)?);
+                    }
+                    cursor.end()?;
+                }
+            }
+        }
+        Ok(Grid::new(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        if row < self.height && column < self.width {
+            Some(&self.cells[row * self.width + column])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at a position that may lie outside of the grid, which repeats in every direction.
+    pub fn get_wrapping(&self, (row, column): (isize, isize)) -> &T {
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let column = column.rem_euclid(self.width as isize) as usize;
+        &self[(row, column)]
+    }
+
+    /// All cells in row-major order.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    /// The position one step in `direction`, if it is inside of the grid.
+    pub fn step(&self, (row, column): Position, (down, right): Direction) -> Option<Position> {
+        let row = (row as isize + down) as usize;
+        let column = (column as isize + right) as usize;
+        if row < self.height && column < self.width {
+            Some((row, column))
+        } else {
+            None
+        }
+    }
+
+    /// The position one step in `direction`, leaving the grid on one side re-enters it on the
+    /// other.
+    pub fn step_wrapping(&self, (row, column): Position, (down, right): Direction) -> Position {
+        let row = (row as isize + down).rem_euclid(self.height as isize) as usize;
+        let column = (column as isize + right).rem_euclid(self.width as isize) as usize;
+        (row, column)
+    }
+
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &ORTHOGONAL)
+    }
+
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &ALL_DIRECTIONS)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        position: Position,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = Position> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&direction| self.step(position, direction))
+    }
+
+    /// The positions seen when looking from `start` in `direction`, up to the edge of the grid.
+    pub fn ray(
+        &self,
+        start: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.step(start, direction), move |&position| {
+            self.step(position, direction)
+        })
+    }
+
+    pub fn map<U>(&self, convert: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(convert).collect(),
+        }
+    }
+
+    /// Builds a grid of the given size, taking every cell from a position of this grid.
+    fn rearrange(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Grid::new(width, cells)
+    }
+
+    /// Mirrors the grid along the diagonal from the top left corner.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |(row, column)| (column, row))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearrange(self.height, self.width, |(row, column)| {
+            (height - 1 - column, row)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearrange(self.height, self.width, |(row, column)| {
+            (column, width - 1 - row)
+        })
+    }
+
+    /// Mirrors the grid from left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearrange(self.width, self.height, |(row, column)| {
+            (row, width - 1 - column)
+        })
+    }
+
+    /// Mirrors the grid from top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearrange(self.width, self.height, |(row, column)| {
+            (height - 1 - row, column)
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): Position) -> &Self::Output {
+        assert!(column < self.width, "column {} out of bounds", column);
+        &self.cells[row * self.width + column]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, column): Position) -> &mut Self::Output {
+        assert!(column < self.width, "column {} out of bounds", column);
+        &mut self.cells[row * self.width + column]
+    }
+}
+
+/// Writes one line per row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(input: &str) -> Grid<char> {
+        Grid::parse(input, "a letter", |c| {
+            Some(c).filter(char::is_ascii_alphabetic)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn reports_ragged_rows() {
+        let parse = |input| {
+            Grid::parse(input, "`.` or `#`", |c| {
+                Some(c).filter(|c| *c == '.' || *c == '#')
+            })
+        };
+
+        let short = parse("..#\n.#\n").unwrap_err();
+        assert_eq!((short.line, short.column), (2, 3));
+        assert_eq!(short.expected, "`.` or `#`");
+
+        let long = parse("..#\n.#..\n").unwrap_err();
+        assert_eq!((long.line, long.column), (2, 4));
+        assert_eq!(long.expected, "end of line");
+    }
+
+    #[test]
+    fn finds_neighbours_inside_the_grid() {
+        let grid = letters("abc\ndef\nghi");
+        assert_eq!(grid.neighbours4((0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn casts_rays_up_to_the_edge() {
+        let grid = letters("abc\ndef\nghi");
+        let seen: String = grid
+            .ray((2, 0), (-1, 1))
+            .map(|position| grid[position])
+            .collect();
+        assert_eq!(seen, "ec");
+        assert_eq!(*grid.get_wrapping((-1, 4)), 'h');
+        assert_eq!(grid.step_wrapping((0, 0), (0, -1)), (0, 2));
+    }
+
+    #[test]
+    fn rotates_and_flips() {
+        let grid = letters("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+    }
+}
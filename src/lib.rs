@@ -0,0 +1,136 @@
+//! Solutions to [Advent of Code 2020](https://adventofcode.com/2020), one module per day.
+
+pub mod grid;
+mod parameters;
+pub mod parsing;
+mod solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day22;
+
+pub use parameters::Parameters;
+pub use parsing::ParseError;
+pub use solution::{Answer, Day, Part, Solver, Timings};
+
+pub struct Puzzle {
+    pub day: u8,
+    configure: parameters::Configure,
+}
+
+impl Puzzle {
+    /// The solver of the day, using `parameters` in place of the puzzle's own values.
+    pub fn solver(&self, parameters: &Parameters) -> Result<Box<dyn Solver>, String> {
+        (self.configure)(parameters.of_day(self.day))
+            .map_err(|error| format!("invalid parameters for day {}: {}", self.day, error))
+    }
+}
+
+pub static PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        day: 1,
+        configure: parameters::configurable::<day01::Day01>,
+    },
+    Puzzle {
+        day: 2,
+        configure: parameters::configurable::<day02::Day02>,
+    },
+    Puzzle {
+        day: 3,
+        configure: parameters::configurable::<day03::Day03>,
+    },
+    Puzzle {
+        day: 4,
+        configure: parameters::configurable::<day04::Day04>,
+    },
+    Puzzle {
+        day: 5,
+        configure: parameters::configurable::<day05::Day05>,
+    },
+    Puzzle {
+        day: 6,
+        configure: parameters::fixed::<day06::Day06>,
+    },
+    Puzzle {
+        day: 7,
+        configure: parameters::configurable::<day07::Day07>,
+    },
+    Puzzle {
+        day: 8,
+        configure: parameters::fixed::<day08::Day08>,
+    },
+    Puzzle {
+        day: 9,
+        configure: parameters::configurable::<day09::Day09>,
+    },
+    Puzzle {
+        day: 10,
+        configure: parameters::fixed::<day10::Day10>,
+    },
+    Puzzle {
+        day: 11,
+        configure: parameters::fixed::<day11::Day11>,
+    },
+    Puzzle {
+        day: 12,
+        configure: parameters::fixed::<day12::Day12>,
+    },
+    Puzzle {
+        day: 13,
+        configure: parameters::fixed::<day13::Day13>,
+    },
+    Puzzle {
+        day: 14,
+        configure: parameters::fixed::<day14::Day14>,
+    },
+    Puzzle {
+        day: 15,
+        configure: parameters::configurable::<day15::Day15>,
+    },
+    Puzzle {
+        day: 16,
+        configure: parameters::configurable::<day16::Day16>,
+    },
+    Puzzle {
+        day: 17,
+        configure: parameters::configurable::<day17::Day17>,
+    },
+    Puzzle {
+        day: 18,
+        configure: parameters::fixed::<day18::Day18>,
+    },
+    Puzzle {
+        day: 19,
+        configure: parameters::fixed::<day19::Day19>,
+    },
+    Puzzle {
+        day: 20,
+        configure: parameters::fixed::<day20::Day20>,
+    },
+    Puzzle {
+        day: 22,
+        configure: parameters::fixed::<day22::Day22>,
+    },
+];
+
+pub fn puzzle(day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
@@ -0,0 +1,147 @@
+//! Parameters of the puzzles, such as the target sum of day 1, that can be changed without editing
+//! the solutions.
+
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Deserializer,
+};
+use toml::{value::Table, Value};
+
+use crate::{Day, Solver};
+
+/// Parameters of all days, read from TOML with a table per day, like `[day01]`.
+/// Missing parameters keep the values of the puzzle.
+#[derive(Debug, Clone, Default)]
+pub struct Parameters(Table);
+
+impl Parameters {
+    pub fn parse(document: &str) -> Result<Parameters, String> {
+        let tables: Table = toml::from_str(document).map_err(|error| error.to_string())?;
+        for (key, value) in &tables {
+            day_of_key(key)?;
+            if !value.is_table() {
+                return Err(format!("`{}` must be a table", key));
+            }
+        }
+        Ok(Parameters(tables))
+    }
+
+    /// Overrides a parameter given as `<day>.<name>=<value>`, like `day01.target=2021`.
+    /// The value is TOML, but strings may be given without quotes.
+    pub fn set(&mut self, assignment: &str) -> Result<(), String> {
+        let malformed = || format!("`{}` is not of the form `<day>.<name>=<value>`", assignment);
+        let (path, raw_value) = assignment.split_once('=').ok_or_else(malformed)?;
+        let (key, name) = path.trim().split_once('.').ok_or_else(malformed)?;
+        day_of_key(key)?;
+
+        let value = match toml::from_str::<Table>(&format!("value = {}", raw_value)) {
+            Ok(mut document) => document.remove("value").ok_or_else(malformed)?,
+            Err(_) => Value::String(raw_value.trim().to_string()),
+        };
+        self.0
+            .entry(key)
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| format!("`{}` must be a table", key))?
+            .insert(name.trim().to_string(), value);
+        Ok(())
+    }
+
+    /// The table of a single day, which is empty if nothing was changed.
+    pub(crate) fn of_day(&self, day: u8) -> Value {
+        self.0
+            .get(&key_of_day(day))
+            .cloned()
+            .unwrap_or_else(|| Value::Table(Table::new()))
+    }
+}
+
+fn key_of_day(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn day_of_key(key: &str) -> Result<u8, String> {
+    key.strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .filter(|&day| crate::puzzle(day).is_some() && key == key_of_day(day))
+        .ok_or_else(|| format!("`{}` is not a solved day like `day01`", key))
+}
+
+/// Creates the solver of a day from the table of its parameters.
+pub(crate) type Configure = fn(Value) -> Result<Box<dyn Solver>, String>;
+
+/// Creates a day whose fields are its parameters.
+pub(crate) fn configurable<D>(parameters: Value) -> Result<Box<dyn Solver>, String>
+where
+    D: Day + DeserializeOwned + Send + Sync + 'static,
+{
+    let day: D = parameters.try_into().map_err(|error| error.to_string())?;
+    Ok(Box::new(day))
+}
+
+/// Creates a day that has no parameters.
+pub(crate) fn fixed<D>(parameters: Value) -> Result<Box<dyn Solver>, String>
+where
+    D: Day + Default + Send + Sync + 'static,
+{
+    match parameters.as_table().and_then(|table| table.keys().next()) {
+        Some(name) => Err(format!("there is no parameter `{}`", name)),
+        None => Ok(Box::new(D::default())),
+    }
+}
+
+/// Deserializes a count that has to be at least 1, like a turn or the length of a preamble.
+pub(crate) fn positive<'de, D: Deserializer<'de>>(deserializer: D) -> Result<usize, D::Error> {
+    match usize::deserialize(deserializer)? {
+        0 => Err(de::Error::custom("expected at least 1, found 0")),
+        value => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_values_of_the_file() {
+        let mut parameters =
+            Parameters::parse("[day01]\ntarget = 2020\n[day07]\nbag = 'shiny gold'").unwrap();
+        parameters.set("day01.target=2021").unwrap();
+        parameters.set("day07.bag=light red").unwrap();
+        parameters.set("day03.slopes=[[1, 2]]").unwrap();
+
+        assert_eq!(parameters.of_day(1)["target"], Value::Integer(2021));
+        assert_eq!(
+            parameters.of_day(7)["bag"],
+            Value::String("light red".to_string())
+        );
+        assert_eq!(
+            parameters.of_day(3)["slopes"],
+            Value::Array(vec![Value::Array(vec![
+                Value::Integer(1),
+                Value::Integer(2)
+            ])])
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_days_and_parameters() {
+        assert!(Parameters::parse("[day21]\nx = 1").is_err());
+        assert!(Parameters::default().set("day1.target=2021").is_err());
+
+        let parameters = Parameters::parse("[day02]\nx = 1\n[day09]\npreambel = 5").unwrap();
+        assert!(crate::puzzle(2).unwrap().solver(&parameters).is_err());
+        assert!(crate::puzzle(9).unwrap().solver(&parameters).is_err());
+    }
+
+    #[test]
+    fn rejects_zero_counts() {
+        let parameters = Parameters::parse("[day09]\npreamble = 0").unwrap();
+        let error = crate::puzzle(9).unwrap().solver(&parameters).err().unwrap();
+        assert!(error.contains("expected at least 1, found 0"), "{}", error);
+
+        let mut parameters = Parameters::default();
+        parameters.set("day15.second_turn=0").unwrap();
+        assert!(crate::puzzle(15).unwrap().solver(&parameters).is_err());
+    }
+}
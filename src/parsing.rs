@@ -0,0 +1,424 @@
+//! Helpers for parsing puzzle inputs while keeping track of positions, so that malformed input
+//! can be reported with the offending line and column.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line in the input.
+    pub line: usize,
+    /// 1-based column in characters.
+    pub column: usize,
+    /// Description of what would have been valid at this position.
+    pub expected: String,
+    /// The section of the input the error is in, if the input is split into [`Section`]s.
+    pub section: Option<String>,
+}
+
+impl ParseError {
+    /// Renders the error together with the offending line and a caret pointing at the column.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let source = input.lines().nth(self.line - 1).unwrap_or("");
+        let marker: String = source
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .chain(std::iter::once('^'))
+            .collect();
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        format!(
+            "error: {}\n{} |\n{} | {}\n{} | {}",
+            self, gutter, self.line, source, gutter, marker
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} at line {}, column {}",
+            self.expected, self.line, self.column
+        )?;
+        if let Some(section) = &self.section {
+            write!(f, " in {}", section)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error for input that ends before the parser is done.
+pub fn end_of_input(input: &str, expected: impl Into<String>) -> ParseError {
+    let line = input.split('\n').count();
+    let last_line = input.rsplit('\n').next().unwrap_or("");
+    ParseError {
+        line,
+        column: last_line.trim_end_matches('\r').chars().count() + 1,
+        expected: expected.into(),
+        section: None,
+    }
+}
+
+/// A line of the input without its indentation, remembering where it is located.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// 1-based line in the input.
+    pub number: usize,
+    indentation: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error at the byte `offset` into the trimmed text.
+    pub fn error_at(&self, offset: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: self.indentation + self.text[..offset].chars().count() + 1,
+            expected: expected.into(),
+            section: None,
+        }
+    }
+
+    pub fn cursor(&self) -> Cursor<'a> {
+        Cursor {
+            line: *self,
+            offset: 0,
+        }
+    }
+
+    /// Parses the whole line as a number.
+    pub fn number<T: FromStr>(&self) -> Result<T, ParseError> {
+        let mut cursor = self.cursor();
+        let number = cursor.number()?;
+        cursor.end()?;
+        Ok(number)
+    }
+}
+
+/// The trimmed, non-empty lines of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines_trimmed_by(input, str::trim)
+}
+
+/// The non-empty lines of the input, trimmed only at the start, for inputs whose lines may end
+/// in significant whitespace.
+pub fn lines_keeping_trailing_whitespace(input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines_trimmed_by(input, str::trim_start)
+}
+
+fn lines_trimmed_by(input: &str, trim: fn(&str) -> &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(index, raw)| {
+            let text = trim(raw);
+            let leading = raw.len() - raw.trim_start().len();
+            Line {
+                number: index + 1,
+                indentation: raw[..leading].chars().count(),
+                text,
+            }
+        })
+        .filter(|line| !line.text.trim_end().is_empty())
+}
+
+/// The trimmed, non-empty lines of the input, grouped into blocks separated by blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks: Vec<Vec<Line>> = Vec::new();
+    let mut previous = 0;
+    for line in lines(input) {
+        match blocks.last_mut() {
+            Some(block) if line.number == previous + 1 => block.push(line),
+            _ => blocks.push(vec![line]),
+        }
+        previous = line.number;
+    }
+    blocks
+}
+
+/// A block of the input, which may start with a header line such as `Tile 2311:`.
+#[derive(Debug, Clone)]
+pub struct Section<'a> {
+    /// 1-based position among the sections of the input.
+    pub number: usize,
+    pub header: Option<Header<'a>>,
+    /// The lines below the header.
+    pub lines: Vec<Line<'a>>,
+}
+
+/// A line like `nearby tickets:` or `Player 1:`, whose trailing number is captured as its id.
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    pub line: Line<'a>,
+    pub name: &'a str,
+    pub id: Option<u64>,
+}
+
+impl<'a> Header<'a> {
+    /// Recognizes a line that ends with its only colon.
+    fn detect(line: Line<'a>) -> Option<Header<'a>> {
+        let title = line.text.strip_suffix(':')?;
+        if title.contains(':') {
+            return None;
+        }
+        let (name, id) = match title.rsplit_once(' ') {
+            Some((name, id)) => match id.parse() {
+                Ok(id) => (name.trim_end(), Some(id)),
+                Err(_) => (title, None),
+            },
+            None => (title, None),
+        };
+        Some(Header { line, name, id })
+    }
+}
+
+/// Splits the input into sections at blank lines, detecting their headers.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    blocks(input)
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut lines)| {
+            let header = Header::detect(lines[0]);
+            if header.is_some() {
+                lines.remove(0);
+            }
+            Section {
+                number: index + 1,
+                header,
+                lines,
+            }
+        })
+        .collect()
+}
+
+impl<'a> Section<'a> {
+    /// The header line, or the first line if there is no header.
+    pub fn first_line(&self) -> Line<'a> {
+        self.header
+            .map(|header| header.line)
+            .unwrap_or_else(|| self.lines[0])
+    }
+
+    /// Requires the header to be exactly `<name>:`.
+    pub fn expect_header(&self, name: &str) -> Result<(), ParseError> {
+        self.parse(|section| {
+            let mut cursor = section.first_line().cursor();
+            cursor.tag(name)?;
+            cursor.tag(":")?;
+            cursor.end()
+        })
+    }
+
+    /// Requires the header to be `<name> <id>:`, and returns the id.
+    pub fn expect_id<T: FromStr>(&self, name: &str) -> Result<T, ParseError> {
+        self.parse(|section| {
+            let mut cursor = section.first_line().cursor();
+            cursor.tag(name)?;
+            cursor.tag(" ")?;
+            let id = cursor.number()?;
+            cursor.tag(":")?;
+            cursor.end()?;
+            Ok(id)
+        })
+    }
+
+    /// Runs `parse` on this section, attributing its errors to the section.
+    pub fn parse<T>(
+        &self,
+        parse: impl FnOnce(&Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parse(self).map_err(|mut error| {
+            error.section.get_or_insert_with(|| self.to_string());
+            error
+        })
+    }
+}
+
+impl Display for Section<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "section {}", self.number)?;
+        if let Some(header) = self.header {
+            write!(f, " (`{}`)", header.line.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a single line from left to right.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: Line<'a>,
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn rest(&self) -> &'a str {
+        &self.line.text[self.offset..]
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// Error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.line.error_at(self.offset, expected)
+    }
+
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(tag) {
+            self.offset += tag.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}`", tag)))
+        }
+    }
+
+    /// Consumes the tag if it is next, and reports whether it was.
+    pub fn optional_tag(&mut self, tag: &str) -> bool {
+        self.tag(tag).is_ok()
+    }
+
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest
+            .char_indices()
+            .find(|(_, c)| !predicate(*c))
+            .map(|(index, _)| index)
+            .unwrap_or_else(|| rest.len());
+        self.offset += length;
+        &rest[..length]
+    }
+
+    /// Like [`Cursor::take_while`], but requires at least one character.
+    pub fn take_while1(
+        &mut self,
+        expected: &str,
+        predicate: impl Fn(char) -> bool,
+    ) -> Result<&'a str, ParseError> {
+        let start = self.clone();
+        let taken = self.take_while(predicate);
+        if taken.is_empty() {
+            Err(start.error(expected))
+        } else {
+            Ok(taken)
+        }
+    }
+
+    /// Consumes a single character, if `convert` accepts it.
+    pub fn char<T>(
+        &mut self,
+        expected: &str,
+        convert: impl Fn(char) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        let result = self
+            .peek()
+            .and_then(|c| convert(c).map(|converted| (converted, c.len_utf8())));
+        match result {
+            Some((converted, length)) => {
+                self.offset += length;
+                Ok(converted)
+            }
+            None => Err(self.error(expected)),
+        }
+    }
+
+    /// Consumes an unsigned decimal number.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.clone();
+        self.take_while1("a number", |c| c.is_ascii_digit())?
+            .parse()
+            .map_err(|_| start.error("a smaller number"))
+    }
+
+    /// Requires that the whole line has been consumed.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_position_in_untrimmed_input() {
+        let input = "1\n\n   12a\n";
+        let error = lines(input)
+            .map(|line| line.number::<u64>())
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 3,
+                column: 6,
+                expected: "end of line".to_string(),
+                section: None,
+            }
+        );
+    }
+
+    #[test]
+    fn renders_caret_below_column() {
+        let error = ParseError {
+            line: 2,
+            column: 5,
+            expected: "`+` or `-`".to_string(),
+            section: None,
+        };
+        assert_eq!(
+            error.diagnostic("nop +0\nacc 12\n"),
+            "error: expected `+` or `-` at line 2, column 5\n  |\n2 | acc 12\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn splits_blocks_at_blank_lines() {
+        let input = "a\nb\n  \nc\n\n\nd";
+        let numbers: Vec<Vec<usize>> = blocks(input)
+            .iter()
+            .map(|block| block.iter().map(|line| line.number).collect())
+            .collect();
+        assert_eq!(numbers, vec![vec![1, 2], vec![4], vec![7]]);
+    }
+
+    #[test]
+    fn captures_section_headers() {
+        let input = "Tile 2311:\n..#\n\nyour ticket:\n7,1,14\n\nclass: 1-3\nrow: 6-11";
+        let sections = sections(input);
+        let headers: Vec<_> = sections
+            .iter()
+            .map(|section| section.header.map(|header| (header.name, header.id)))
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                Some(("Tile", Some(2311))),
+                Some(("your ticket", None)),
+                None
+            ]
+        );
+        assert_eq!(sections[2].lines.len(), 2);
+        assert_eq!(sections[0].expect_id("Tile"), Ok(2311));
+
+        let error = sections[1].expect_header("nearby tickets").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.section.as_deref(), Some("section 2 (`your ticket:`)"));
+    }
+}
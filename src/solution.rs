@@ -0,0 +1,150 @@
+use std::{
+    fmt::{self, Display},
+    hint,
+    time::{Duration, Instant},
+};
+
+use crate::parsing::ParseError;
+
+/// A puzzle of a single day, split into parsing the input and solving both parts.
+pub trait Day {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(&self, input: &Self::Input) -> Answer;
+    /// Returns `None` if the second part has not been solved yet.
+    fn part_two(&self, input: &Self::Input) -> Option<Answer>;
+
+    /// The names of the reports that [`Day::report`] writes.
+    const REPORTS: &'static [&'static str] = &[];
+
+    /// Writes a report about the input, like why the entries counted by a part do or don't
+    /// qualify. Returns `None` if `name` is not one of [`Day::REPORTS`].
+    fn report(&self, _input: &Self::Input, _name: &str) -> Option<String> {
+        None
+    }
+
+    /// Parses the input for the report called `name`. Days whose reports need more from the
+    /// input than the parts do override this.
+    fn parse_for_report(&self, input: &str, _name: &str) -> Result<Self::Input, ParseError> {
+        self.parse(input)
+    }
+}
+
+/// Object-safe view of a [`Day`], so that days with different inputs can be run alike.
+pub trait Solver: Send + Sync {
+    fn solve(&self, input: &str, part: Part) -> Result<Option<Answer>, ParseError>;
+    /// Parses the input once and solves both parts, measuring each step on its own.
+    fn time(&self, input: &str) -> Result<Timings, ParseError>;
+    fn reports(&self) -> &'static [&'static str];
+    /// Parses the input and writes the report called `name`, if there is one.
+    fn report(&self, input: &str, name: &str) -> Result<Option<String>, ParseError>;
+}
+
+/// How long the steps of solving a day took.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    /// `None` if the second part has not been solved yet.
+    pub part_two: Option<Duration>,
+}
+
+impl<D> Solver for D
+where
+    D: Day + Send + Sync,
+{
+    fn solve(&self, input: &str, part: Part) -> Result<Option<Answer>, ParseError> {
+        let parsed = self.parse(input)?;
+        Ok(match part {
+            Part::One => Some(self.part_one(&parsed)),
+            Part::Two => self.part_two(&parsed),
+        })
+    }
+
+    fn time(&self, input: &str) -> Result<Timings, ParseError> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        hint::black_box(self.part_one(&parsed));
+        let part_one = start.elapsed();
+
+        let start = Instant::now();
+        let part_two = hint::black_box(self.part_two(&parsed)).map(|_| start.elapsed());
+
+        Ok(Timings {
+            parse,
+            part_one,
+            part_two,
+        })
+    }
+
+    fn reports(&self) -> &'static [&'static str] {
+        D::REPORTS
+    }
+
+    fn report(&self, input: &str, name: &str) -> Result<Option<String>, ParseError> {
+        let parsed = self.parse_for_report(input, name)?;
+        Ok(Day::report(self, &parsed, name))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Part::One => "first",
+            Part::Two => "second",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The input has no answer for the part, for the given reason, like no entries summing up to
+    /// the target. This is not an answer to print or compare as one.
+    Missing(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Missing(reason) => write!(f, "no answer: {}", reason),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(number: $type) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(usize, isize, u64, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}